@@ -46,7 +46,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             .into_rows_result()?
             .first_row::<(i64,)>()?
             .0
-            == n.into()
+            == i64::from(n)
     );
 
     Ok(())
@@ -9,6 +9,7 @@ const metrics = require("./metrics");
 const auth = require("./auth");
 const { throwNotSupported } = require("./new-utils");
 const errors = require("./errors.js");
+const rust = require("../index");
 
 /**
  * Client options.
@@ -58,6 +59,10 @@ const errors = require("./errors.js");
  * the {@link Client} instance.
  *
  * This value is passed to database and is useful as metadata for describing a client connection on the server side.
+ * @property {String} [compression] The wire protocol compression algorithm: `"lz4"` or `"snappy"`.
+ * When not provided, the traffic is not compressed.
+ *
+ * When the algorithm is not supported by a node, connections to that node fall back to no compression.
  * @property {Object} [monitorReporting] Options for reporting mechanism from the client to the DSE server, for
  * versions that support it.
  * [TODO: Add support for this field]
@@ -92,6 +97,11 @@ const errors = require("./errors.js");
  * from the internal cache. Reaching a high threshold hints that the queries are not being reused, like when
  * hard-coding parameter values inside the queries.
  * Default: `512`.
+ * @property {Number} [shutdownTimeout] The maximum amount of time in milliseconds that
+ * [Client#shutdown()]{@link Client#shutdown} waits for the requests in progress to complete.
+ * When the timeout expires, a warning is logged and the connections are closed once the remaining requests complete.
+ * Use `0` to wait without a limit.
+ * Default: `10000`.
  * @property {Object} [policies]
  * [TODO: Add support for this field]
  * @property {LoadBalancingPolicy} [policies.loadBalancing] The load balancing policy instance to be used to determine
@@ -100,9 +110,19 @@ const errors = require("./errors.js");
  * @property {RetryPolicy} [policies.retry] The retry policy.
  * [TODO: Add support for this field]
  * @property {ReconnectionPolicy} [policies.reconnection] The reconnection policy to be used.
- * [TODO: Add support for this field]
+ *
+ * It controls the delays between attempts to open connections to the hosts. Only
+ * [ConstantReconnectionPolicy]{@link module:policies/reconnection~ConstantReconnectionPolicy} and
+ * [ExponentialReconnectionPolicy]{@link module:policies/reconnection~ExponentialReconnectionPolicy}
+ * are supported. Defaults to the ExponentialReconnectionPolicy with a base delay of 1 second
+ * and a max delay of 10 minutes.
  * @property {AddressTranslator} [policies.addressResolution] The address resolution policy.
- * [TODO: Add support for this field]
+ *
+ * [StaticAddressTranslator]{@link module:policies/addressResolution~StaticAddressTranslator} is
+ * handled directly by the Rust driver. Other translators (like
+ * [EC2MultiRegionTranslator]{@link module:policies/addressResolution~EC2MultiRegionTranslator}) are called
+ * asynchronously each time the address of a peer has to be translated. When such translator doesn't
+ * call back within 10 seconds, the translation fails and the driver doesn't connect to the peer.
  * @property {SpeculativeExecutionPolicy} [policies.speculativeExecution] The `SpeculativeExecutionPolicy`
  * instance to be used to determine if the client should send speculative queries when the selected host takes more
  * time than expected.
@@ -118,26 +138,45 @@ const errors = require("./errors.js");
  *
  * Use `null` to disable client-side timestamp generation.
  *
- * [TODO: Add support for this field]
+ * Timestamps are generated by the Rust driver, so only
+ * [MonotonicTimestampGenerator]{@link module:policies/timestampGeneration~MonotonicTimestampGenerator}
+ * is currently supported.
  * @property {QueryOptions} [queryOptions] Default options for all queries.
  * [TODO: Add support for this field]
  * @property {Object} [pooling] Pooling options.
- * [TODO: Add support for this field]
  * @property {Number} [pooling.heartBeatInterval] The amount of idle time in milliseconds that has to pass before the
  * driver issues a request on an active connection to avoid idle time disconnections. Default: 30000.
- * [TODO: Add support for this field]
+ *
+ * Use `0` to disable the heart beats.
+ * @property {Number} [pooling.heartBeatTimeout] The amount of time in milliseconds the driver waits for the
+ * response to a heart beat request, before the connection is closed. Default: 30000.
+ *
+ * Use `0` to disable the timeout.
  * @property {Object} [pooling.coreConnectionsPerHost] Associative array containing amount of connections per host
  * distance.
- * [TODO: Add support for this field]
- * @property {Number} [pooling.maxRequestsPerConnection] The maximum number of requests per connection. The default
- * value is:
- * - For modern protocol versions (v3 and above): 2048
- * - For older protocol versions (v1 and v2): 128
  *
- * [TODO: Add support for this field]
+ * The Rust driver uses the same pool size for all the hosts, so only the amount of
+ * connections for the `local` distance is used. It cannot be provided together with
+ * `pooling.coreConnectionsPerShard`.
+ * @property {Number} [pooling.coreConnectionsPerShard] Amount of connections to each shard of the host.
+ * Applies only to ScyllaDB clusters, for other clusters it is equivalent to the amount of connections per host.
+ *
+ * When neither this option nor `pooling.coreConnectionsPerHost` are provided, a single connection per shard is used.
+ * @property {Boolean} [pooling.disallowShardAwarePort] Prevents the driver from connecting to the shard-aware port,
+ * even if the node supports it. This is a ScyllaDB-specific option, which should be changed only if the
+ * shard-aware port is not reachable from the client. Default: false.
+ * @property {Object} [pooling.shardAwareLocalPortRange] The range of local ports used when connecting to the
+ * shard-aware port, as an object with `min` and `max` properties (both inclusive). The range must be within
+ * [1024, 65535]. Default: `{ min: 49152, max: 65535 }`.
+ * @property {Number} [pooling.maxRequestsPerConnection] The maximum number of requests per connection.
+ *
+ * Not supported, the value is ignored. The Rust driver has no such limit, and sends up to 32768 concurrent
+ * requests (all the stream ids available in the protocol) on each connection. To limit the load of a single
+ * connection, increase the amount of connections with `pooling.coreConnectionsPerShard`.
  * @property {Boolean} [pooling.warmup] Determines if all connections to hosts in the local datacenter must be opened on
  * connect. Default: true.
- * [TODO: Add support for this field]
+ *
+ * The Rust driver always initializes the connection pools of all the hosts on connect.
  * @property {Object} [protocolOptions]
  * [TODO: Add support for this field]
  * @property {Number} [protocolOptions.port] The port to use to connect to the Cassandra host. If not set through this
@@ -161,16 +200,15 @@ const errors = require("./errors.js");
  *
  * [TODO: Add support for this field]
  * @property {Object} [socketOptions]
- * [TODO: Add support for this field]
  * @property {Number} [socketOptions.connectTimeout] Connection timeout in milliseconds. Default: 5000.
- * [TODO: Add support for this field]
  * @property {Number} [socketOptions.defunctReadTimeoutThreshold] Determines the amount of requests that simultaneously
  * have to timeout before closing the connection. Default: 64.
  * [TODO: Add support for this field]
  * @property {Boolean} [socketOptions.keepAlive] Whether to enable TCP keep-alive on the socket. Default: true.
- * [TODO: Add support for this field]
  * @property {Number} [socketOptions.keepAliveDelay] TCP keep-alive delay in milliseconds. Default: 0.
- * [TODO: Add support for this field]
+ *
+ * The Rust driver cannot enable TCP keep-alive with the system default delay, so the delay of 0
+ * is replaced with 2 hours, the default delay of Linux.
  * @property {Number} [socketOptions.readTimeout] Per-host read timeout in milliseconds.
  *
  * Please note that this is not the maximum time a call to {@link Client#execute} may have to wait;
@@ -188,7 +226,6 @@ const errors = require("./errors.js");
  * Setting a value of 0 disables read timeouts. Default: `12000`.
  * [TODO: Add support for this field]
  * @property {Boolean} [socketOptions.tcpNoDelay] When set to true, it disables the Nagle algorithm. Default: true.
- * [TODO: Add support for this field]
  * @property {Number} [socketOptions.coalescingThreshold] Buffer length in bytes use by the write queue before flushing
  * the frames. Default: 8000.
  * [TODO: Add support for this field]
@@ -206,10 +243,13 @@ const errors = require("./errors.js");
  * [TODO: Add support for this field]
  * @property {Function} [encoding.map] Map constructor to use for Cassandra map<k,v> type encoding and decoding.
  * If not set, it will default to Javascript Object with map keys as property names.
- * [TODO: Add support for this field]
+ *
+ * Keys of the maps are decoded into the same types as other values, so keys like uuid, timestamp or tuple
+ * are only preserved when using a Map constructor, as the property names of an Object are always strings.
+ * The option can be overridden for a single execution with the `encoding` query option.
  * @property {Function} [encoding.set] Set constructor to use for Cassandra set<k> type encoding and decoding.
  * If not set, it will default to Javascript Array.
- * [TODO: Add support for this field]
+ * The option can be overridden for a single execution with the `encoding` query option.
  * @property {Boolean} [encoding.copyBuffer] Determines if the network buffer should be copied for buffer based data
  * types (blob, uuid, timeuuid and inet).
  *
@@ -240,6 +280,36 @@ const errors = require("./errors.js");
  *
  * Note, that using Integer as Varint (`useBigIntAsVarint == false`) is deprecated.
  * [TODO: Add support for this field]
+ * @property {string} [encoding.timestampDecoding] Representation of the CQL timestamp values in the results:
+ * - `"date"`: [Date]{@link https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date}
+ * object. Timestamps outside of the range supported by Date are decoded as invalid dates.
+ * - `"number"`: number of milliseconds since the epoch. Timestamps above 2^53 milliseconds lose precision.
+ * - `"bigint"`: BigInt number of milliseconds since the epoch, representing all the timestamps exactly.
+ *
+ * The values are created directly when reading the response, without a separate conversion step.
+ * The option can be overridden for a single execution with the `encoding` query option.
+ * Default: `"date"`.
+ * @property {Object<string, Function>} [encoding.udtConstructors] Constructors of the user defined types,
+ * keyed by the name of the type qualified with its keyspace, for example `"ks.address"`.
+ *
+ * Values of the registered types, including the ones nested in collections, tuples and other user defined types,
+ * are created with `new` and the constructor called without arguments, with the fields assigned afterwards.
+ * Values of the other types are created as plain objects.
+ *
+ * The constructor must create extensible objects with writable fields, as the fields are assigned afterwards.
+ * For immutable values, for example frozen objects, use `udtFactories` instead.
+ *
+ * Regardless of the constructor, the name of the type is available in the non-enumerable `udtName` property
+ * of the value, for example `"ks.address"`, unless the class of the constructor defines this property on its own.
+ * The option can be overridden for a single execution with the `encoding` query option.
+ * @property {Object<string, Function>} [encoding.udtFactories] Factories of the user defined types,
+ * keyed by the name of the type qualified with its keyspace, for example `"ks.address"`.
+ *
+ * Values of the registered types are created by calling the factory with a plain object with the fields
+ * of the value, already decoded, and with the `udtName` property. The value returned from the factory is used
+ * as the value of the type, so the factories can create immutable values.
+ * A type can't be registered with both a constructor and a factory.
+ * The option can be overridden for a single execution with the `encoding` query option.
  * @property {Array.<ExecutionProfile>} [profiles] The array of [execution profiles]{@link ExecutionProfile}.
  * [TODO: Add support for this field]
  * @property {Function} [promiseFactory] Function to be used to create a `Promise` from a
@@ -321,6 +391,7 @@ function defaultOptions() {
         metrics: new metrics.DefaultMetrics(),
         maxPrepared: null, // Default is 512, defined on the Rust side
         refreshSchemaDelay: 1000,
+        shutdownTimeout: 10000,
         isMetadataSyncEnabled: true,
         prepareOnAllHosts: true,
         rePrepareOnUp: true,
@@ -404,6 +475,13 @@ function extend(baseOptions, userOptions) {
 
     validateSocketOptions(options.socketOptions);
 
+    if (
+        typeof options.shutdownTimeout !== "number" ||
+        !(options.shutdownTimeout >= 0)
+    ) {
+        throw new TypeError("shutdownTimeout must be a non-negative Number");
+    }
+
     validateAuthenticationOptions(options);
 
     options.encoding = options.encoding || {};
@@ -579,6 +657,49 @@ function validateEncodingOptions(encodingOptions) {
         }
     }
 
+    if (
+        encodingOptions.timestampDecoding !== undefined &&
+        !["date", "number", "bigint"].includes(
+            encodingOptions.timestampDecoding,
+        )
+    ) {
+        throw new TypeError(
+            `Timestamp decoding ${encodingOptions.timestampDecoding} not valid`,
+        );
+    }
+
+    if (encodingOptions.udtConstructors) {
+        for (const [name, constructor] of Object.entries(
+            encodingOptions.udtConstructors,
+        )) {
+            if (typeof constructor !== "function") {
+                throw new TypeError(
+                    `Constructor of the user defined type ${name} not valid`,
+                );
+            }
+        }
+    }
+
+    if (encodingOptions.udtFactories) {
+        for (const [name, factory] of Object.entries(
+            encodingOptions.udtFactories,
+        )) {
+            if (typeof factory !== "function") {
+                throw new TypeError(
+                    `Factory of the user defined type ${name} not valid`,
+                );
+            }
+            if (
+                encodingOptions.udtConstructors &&
+                encodingOptions.udtConstructors[name]
+            ) {
+                throw new TypeError(
+                    `User defined type ${name} can't have both a constructor and a factory`,
+                );
+            }
+        }
+    }
+
     if (
         (encodingOptions.useBigIntAsLong ||
             encodingOptions.useBigIntAsVarint) &&
@@ -658,10 +779,14 @@ function setRustOptions(options) {
     rustOptions.applicationVersion = options.applicationVersion;
     rustOptions.keyspace = options.keyspace;
     rustOptions.cacheSize = options.maxPrepared;
+    rustOptions.compression = options.compression;
     if (options.credentials) {
         rustOptions.credentialsUsername = options.credentials.username;
         rustOptions.credentialsPassword = options.credentials.password;
-    } else if (options.authProvider) {
+    } else if (
+        options.authProvider &&
+        !(options.authProvider instanceof auth.NoAuthProvider)
+    ) {
         if (options.authProvider instanceof auth.PlainTextAuthProvider) {
             rustOptions.credentialsUsername = options.authProvider.username;
             rustOptions.credentialsPassword = options.authProvider.password;
@@ -675,11 +800,144 @@ function setRustOptions(options) {
     if (options.sslOptions) {
         rustOptions.sslOptions = options.sslOptions;
     }
+    rustOptions.reconnectionPolicy = setRustReconnectionPolicy(
+        options.policies,
+    );
+    rustOptions.timestampGenerator = setRustTimestampGenerator(
+        options.policies,
+    );
+    setRustAddressTranslation(rustOptions, options.policies);
+    rustOptions.pooling = setRustPooling(options.pooling);
+    // Socket options are always provided, so that keep-alive is enabled by default
+    const socketOptions = options.socketOptions || {};
+    rustOptions.socketOptions = {
+        connectTimeout: socketOptions.connectTimeout,
+        keepAlive: socketOptions.keepAlive,
+        keepAliveDelay: socketOptions.keepAliveDelay,
+        tcpNoDelay: socketOptions.tcpNoDelay,
+    };
     return rustOptions;
 }
 
+/**
+ * Create rust reconnection policy options using js reconnection policy
+ * @param {Object} [policiesOptions]
+ * @returns {Object} Options of the policy
+ * @private
+ */
+function setRustReconnectionPolicy(policiesOptions) {
+    let policy = policiesOptions ? policiesOptions.reconnection : undefined;
+    if (!policy) {
+        policy = policies.defaultReconnectionPolicy();
+    }
+    if (policy instanceof policies.reconnection.ConstantReconnectionPolicy) {
+        return {
+            kind: rust.ReconnectionPolicyKind.Constant,
+            delay: policy.delay,
+        };
+    }
+    if (policy instanceof policies.reconnection.ExponentialReconnectionPolicy) {
+        return {
+            kind: rust.ReconnectionPolicyKind.Exponential,
+            baseDelay: policy.baseDelay,
+            maxDelay: policy.maxDelay,
+            startWithNoDelay: policy.startWithNoDelay === true,
+        };
+    }
+    throw new errors.ArgumentError(
+        // TODO: Add support for custom reconnection policies
+        "Unsupported reconnection policy: " + policy,
+    );
+}
+
+/**
+ * Create rust timestamp generator options using js timestamp generation policy
+ * @param {Object} [policiesOptions]
+ * @returns {Object|undefined} Options of the generator, or undefined when client side timestamps are disabled
+ * @private
+ */
+function setRustTimestampGenerator(policiesOptions) {
+    let generator = policiesOptions
+        ? policiesOptions.timestampGeneration
+        : undefined;
+    if (generator === undefined) {
+        generator = policies.defaultTimestampGenerator();
+    }
+    if (generator === null) {
+        // Timestamps are generated by the server
+        return undefined;
+    }
+    const { MonotonicTimestampGenerator } = policies.timestampGeneration;
+    // Subclasses can override the generation of timestamps, which is done in Rust
+    if (
+        Object.getPrototypeOf(generator) !==
+        MonotonicTimestampGenerator.prototype
+    ) {
+        throw new errors.ArgumentError(
+            // TODO: Add support for custom timestamp generators
+            "Unsupported timestamp generator: " + generator,
+        );
+    }
+    return {
+        warningThreshold: generator.warningThreshold,
+        // In JS generator, values under 1 disable logging
+        minLogInterval: Math.max(generator.minLogInterval, 0),
+    };
+}
+
+/**
+ * Set rust address translation options using js address resolution policy
+ * @param {Object} rustOptions
+ * @param {Object} [policiesOptions]
+ * @private
+ */
+function setRustAddressTranslation(rustOptions, policiesOptions) {
+    const translator = policiesOptions
+        ? policiesOptions.addressResolution
+        : undefined;
+    if (
+        !translator ||
+        translator.constructor === policies.addressResolution.AddressTranslator
+    ) {
+        // Default translator does not change the addresses
+        return;
+    }
+    if (
+        translator instanceof
+        policies.addressResolution.StaticAddressTranslator
+    ) {
+        rustOptions.addressMap = translator.addressMap;
+        return;
+    }
+    rustOptions.addressTranslator = (address, port) =>
+        new Promise((resolve) => translator.translate(address, port, resolve));
+}
+
+/**
+ * Create rust pooling options using js pooling options
+ * @param {Object} [pooling]
+ * @returns {Object|undefined}
+ * @private
+ */
+function setRustPooling(pooling) {
+    if (!pooling) {
+        return undefined;
+    }
+    return {
+        coreConnectionsPerHost: pooling.coreConnectionsPerHost
+            ? pooling.coreConnectionsPerHost[types.distance.local]
+            : undefined,
+        coreConnectionsPerShard: pooling.coreConnectionsPerShard,
+        disallowShardAwarePort: pooling.disallowShardAwarePort,
+        shardAwareLocalPortRange: pooling.shardAwareLocalPortRange,
+        heartBeatInterval: pooling.heartBeatInterval,
+        heartBeatTimeout: pooling.heartBeatTimeout,
+    };
+}
+
 exports.extend = extend;
 exports.setRustOptions = setRustOptions;
+exports.validateEncodingOptions = validateEncodingOptions;
 exports.defaultOptions = defaultOptions;
 exports.coreConnectionsPerHostV2 = coreConnectionsPerHostV2;
 exports.coreConnectionsPerHostV3 = coreConnectionsPerHostV3;
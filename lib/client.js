@@ -43,6 +43,11 @@ const { HostMap } = require("./host.js");
  */
 class Client extends events.EventEmitter {
     #encoder;
+    /**
+     * Promise of the connection attempt in progress.
+     * @type {Promise<void>}
+     */
+    #connectPromise;
     /**
      * Creates a new instance of {@link Client}.
      * @param {clientOptions.ClientOptions} options The options for this instance.
@@ -116,6 +121,23 @@ class Client extends events.EventEmitter {
         throw new SyntaxError("Client keyspace is read-only");
     }
 
+    /**
+     * Gets the name of the wire protocol compression algorithm configured for the client,
+     * or `undefined` when the compression is not configured.
+     *
+     * This is not the compression negotiated with the nodes, which is not exposed by the Rust driver:
+     * connections to the nodes that don't support the algorithm fall back to no compression.
+     * @type {string | undefined}
+     */
+    get configuredCompression() {
+        const compression = this.rustClient.getConfiguredCompression();
+        return compression === null ? undefined : compression;
+    }
+
+    set configuredCompression(_) {
+        throw new SyntaxError("Client configured compression is read-only");
+    }
+
     /**
      * Gets an associative array of cluster hosts.
      * @type {HostMap}
@@ -156,12 +178,22 @@ class Client extends events.EventEmitter {
     /**
      * Manually prepare query into prepared statement
      * @param {string} query
+     * @param {ExecOptions.ExecutionOptions} execOptions Options of the execution,
+     * the statement is prepared in the keyspace requested with them.
      * @returns {Promise<list<Object | string>>}
      * Returns a tuple of type object (the format expected by the encoder) and prepared statement wrapper
      * @package
      */
-    async prepareQuery(query) {
-        let expectedTypes = await this.rustClient.prepareStatement(query);
+    async prepareQuery(query, execOptions) {
+        let expectedTypes = await this.#withSignal(
+            execOptions,
+            (cancellation) =>
+                this.rustClient.prepareStatement(
+                    query,
+                    execOptions.getRustOptions(),
+                    cancellation,
+                ),
+        );
         let res = [expectedTypes.map((t) => convertComplexType(t)), query];
         return res;
     }
@@ -198,17 +230,25 @@ class Client extends events.EventEmitter {
 
         if (this.isShuttingDown) {
             // it is being shutdown, don't allow further calls to connect()
-            throw new errors.NoHostAvailableError(
-                null,
+            throw new errors.ShutdownError(
                 "Connecting after shutdown is not supported",
             );
         }
 
         if (this.connecting) {
-            return promiseUtils.fromEvent(this, "connected");
+            return this.#connectPromise;
         }
 
         this.connecting = true;
+        this.#connectPromise = this.#createSession();
+        return this.#connectPromise;
+    }
+
+    /**
+     * Creates the Rust session, emitting the `connected` event once it is done.
+     * @private
+     */
+    async #createSession() {
         this.log(
             "info",
             util.format(
@@ -340,7 +380,7 @@ class Client extends events.EventEmitter {
             // If the statement is already prepared, skip the preparation process
             // Otherwise call Rust part to prepare a statement
             if (typeof query === "string") {
-                query = await this.prepareQuery(query);
+                query = await this.prepareQuery(query, execOptions);
             }
 
             /**
@@ -355,10 +395,13 @@ class Client extends events.EventEmitter {
             let encoded = encodeParams(expectedTypes, params, this.#encoder);
 
             // Execute query
-            result = await this.rustClient.executePreparedUnpagedEncoded(
-                statement,
-                encoded,
-                rustOptions,
+            result = await this.#withSignal(execOptions, (cancellation) =>
+                this.rustClient.executePreparedUnpagedEncoded(
+                    statement,
+                    encoded,
+                    rustOptions,
+                    cancellation,
+                ),
             );
         } else {
             // We do not accept already prepared statements for unprepared queries
@@ -374,13 +417,51 @@ class Client extends events.EventEmitter {
             );
 
             // Execute query
-            result = await this.rustClient.queryUnpagedEncoded(
-                query,
-                encoded,
-                rustOptions,
+            result = await this.#withSignal(execOptions, (cancellation) =>
+                this.rustClient.queryUnpagedEncoded(
+                    query,
+                    encoded,
+                    rustOptions,
+                    cancellation,
+                ),
             );
         }
-        return new ResultSet(result);
+        return new ResultSet(
+            result,
+            undefined,
+            execOptions.getEncodingOptions(),
+        );
+    }
+
+    /**
+     * Runs the request with a cancellation handle, that is cancelled when
+     * the signal from the execution options is aborted.
+     * @param {ExecOptions.ExecutionOptions} execOptions
+     * @param {function(rust.CancellationHandle=): Promise} request
+     * @returns {Promise}
+     * @private
+     */
+    async #withSignal(execOptions, request) {
+        const signal = execOptions.getSignal();
+        if (!signal) {
+            return request(undefined);
+        }
+        if (signal.aborted) {
+            throw new errors.AbortError("The request was aborted");
+        }
+        const cancellation = new rust.CancellationHandle();
+        const onAbort = () => cancellation.cancel();
+        signal.addEventListener("abort", onAbort, { once: true });
+        try {
+            return await request(cancellation);
+        } catch (err) {
+            if (cancellation.isCancelled()) {
+                throw new errors.AbortError("The request was aborted");
+            }
+            throw err;
+        } finally {
+            signal.removeEventListener("abort", onAbort);
+        }
     }
 
     /**
@@ -549,7 +630,7 @@ class Client extends events.EventEmitter {
             // If the statement is already prepared, skip the preparation process
             // Otherwise call Rust part to prepare a statement
             if (typeof query === "string") {
-                query = await this.prepareQuery(query);
+                query = await this.prepareQuery(query, execOptions);
             }
 
             /**
@@ -564,11 +645,14 @@ class Client extends events.EventEmitter {
             let encoded = encodeParams(expectedTypes, params, this.#encoder);
 
             // Execute query
-            result = await this.rustClient.executeSinglePageEncoded(
-                statement,
-                encoded,
-                rustOptions,
-                pageState,
+            result = await this.#withSignal(execOptions, (cancellation) =>
+                this.rustClient.executeSinglePageEncoded(
+                    statement,
+                    encoded,
+                    rustOptions,
+                    pageState,
+                    cancellation,
+                ),
             );
         } else {
             // We do not accept already prepared statements for unprepared queries
@@ -583,16 +667,23 @@ class Client extends events.EventEmitter {
             );
 
             // Execute query
-            result = await this.rustClient.querySinglePageEncoded(
-                query,
-                encoded,
-                rustOptions,
-                pageState,
+            result = await this.#withSignal(execOptions, (cancellation) =>
+                this.rustClient.querySinglePageEncoded(
+                    query,
+                    encoded,
+                    rustOptions,
+                    pageState,
+                    cancellation,
+                ),
             );
         }
         // result[0] - information about page state
         // result[1] - object representing result itself
-        let resultSet = new ResultSet(result[1], result[0]);
+        let resultSet = new ResultSet(
+            result[1],
+            result[0],
+            execOptions.getEncodingOptions(),
+        );
         if (result[0].hasNextPage()) {
             resultSet.nextPageAsync = async (pageState) => {
                 return (
@@ -740,7 +831,7 @@ class Client extends events.EventEmitter {
             if (shouldBePrepared) {
                 let prepared = preparedCache.getElement(statement);
                 if (!prepared) {
-                    prepared = await this.prepareQuery(statement);
+                    prepared = await this.prepareQuery(statement, execOptions);
                     preparedCache.storeElement(statement, prepared);
                 }
                 types = prepared[0];
@@ -760,11 +851,62 @@ class Client extends events.EventEmitter {
         let batch = shouldBePrepared
             ? rust.createPreparedBatch(allQueries, rustOptions)
             : rust.createUnpreparedBatch(allQueries, rustOptions);
-        let wrappedResult = await this.rustClient.batchEncoded(
-            batch,
-            parametersRows,
+        let wrappedResult = await this.#withSignal(
+            execOptions,
+            (cancellation) =>
+                this.rustClient.batchEncoded(
+                    batch,
+                    parametersRows,
+                    cancellation,
+                ),
+        );
+        return new ResultSet(
+            wrappedResult,
+            undefined,
+            execOptions.getEncodingOptions(),
+        );
+    }
+
+    /**
+     * Creates an executor of the query, that accepts the parameter sets in chunks,
+     * with the concurrency driven by the Rust driver.
+     *
+     * Pushing a chunk resolves once all of its parameter sets are queued for the execution,
+     * and it resolves to `false` when the execution stopped because of an error.
+     * Only the pushed chunk is encoded, so the memory used by the execution doesn't depend
+     * on the total number of the parameter sets.
+     * The executor must always be finished, to release the resources of the execution.
+     * Once the client starts shutting down, pushing fails with `ShutdownError`.
+     * @param {string} query
+     * @param {ExecOptions.ExecutionOptions} execOptions
+     * @param {{concurrencyLevel: number, raiseOnFirstError: boolean, collectResults: boolean}} concurrency
+     * @returns {Promise<{push: function(Array<Array>): Promise<boolean>, finish: function(): Promise<Object>}>}
+     * @package
+     */
+    async rustyConcurrentExecutor(query, execOptions, concurrency) {
+        if (!this.connected) {
+            await this.#connect();
+        }
+        const [expectedTypes, statement] = await this.prepareQuery(
+            query,
+            execOptions,
         );
-        return new ResultSet(wrappedResult);
+        const executor = await this.rustClient.createConcurrentExecutor(
+            statement,
+            execOptions.getRustOptions(),
+            concurrency.concurrencyLevel,
+            concurrency.raiseOnFirstError,
+            concurrency.collectResults,
+        );
+        return {
+            push: (chunk) =>
+                executor.push(
+                    chunk.map((params) =>
+                        encodeParams(expectedTypes, params, this.#encoder),
+                    ),
+                ),
+            finish: () => executor.finish(),
+        };
     }
 
     /**
@@ -788,17 +930,38 @@ class Client extends events.EventEmitter {
         return ClientState.from(this);
     }
 
+    /**
+     * Gets the counters of the attempts to connect to the hosts, that are made
+     * with the delays from the [reconnection policy]{@link ClientOptions}.
+     *
+     * `failedAttempts` counts the attempts to open the connections to a host that failed,
+     * and `reconnections` counts the times the connections to a host were opened again after such failures.
+     * Both are counted since the client connected.
+     * @returns {{failedAttempts: number, reconnections: number}}
+     */
+    getReconnectionMetrics() {
+        if (!this.rustClient) {
+            return { failedAttempts: 0, reconnections: 0 };
+        }
+        return this.rustClient.getReconnectionMetrics();
+    }
+
     log = utils.log;
 
     /**
-     * The only effect of calling shutdown is rejecting any following queries to the database.
+     * Closes all connections to all hosts.
+     *
+     * New requests are rejected as soon as the shutdown starts. Requests that are already executing
+     * are allowed to complete, waiting for them at most `shutdownTimeout` milliseconds
+     * (see [ClientOptions]{@link ClientOptions}).
+     * If they don't complete in time, a warning is logged and the connections are closed
+     * once the remaining requests complete.
+     *
+     * After the shutdown, any following request fails with `ShutdownError`.
      *
      * It returns a `Promise` when a `callback` is not provided.
      *
      * @param {Function} [callback] Optional callback to be invoked when finished closing all connections.
-     *
-     * @deprecated Explicit connection shutdown is deprecated and may be removed in the future.
-     * Drop this client to close the connection to the database.
      */
     shutdown(callback) {
         return promiseUtils.optionalCallback(this.#shutdown(), callback);
@@ -806,25 +969,32 @@ class Client extends events.EventEmitter {
 
     /** @private */
     async #shutdown() {
-        this.log(
-            "warning",
-            "Explicit shutdown is deprecated and may be removed in the future.\n" +
-                "Drop this client to close the connection to the database.",
-        );
+        if (this.connecting) {
+            this.log("warning", "Shutting down while connecting");
+            // wait until finish connecting for easier troubleshooting
+            try {
+                await this.#connectPromise;
+            } catch (err) {
+                // The failure is reported to the caller of connect(), there is nothing to shut down
+            }
+        }
 
         if (!this.connected) {
             // not initialized
             return;
         }
 
-        if (this.connecting) {
-            this.log("warning", "Shutting down while connecting");
-            // wait until finish connecting for easier troubleshooting
-            await promiseUtils.fromEvent(this, "connected");
-        }
-
         this.connected = false;
         this.isShuttingDown = true;
+
+        const shutdownTimeout = this.options.shutdownTimeout;
+        try {
+            await this.rustClient.close(
+                shutdownTimeout > 0 ? shutdownTimeout : undefined,
+            );
+        } catch (err) {
+            this.log("warning", "Shutdown did not complete: " + err.message);
+        }
     }
 
     /**
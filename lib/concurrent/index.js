@@ -4,6 +4,8 @@ const _Client = require("../client");
 const utils = require("../utils");
 const { Stream } = require("stream");
 const { PreparedCache } = require("../cache");
+const ResultSet = require("../types/result-set");
+const { mapNapiError } = require("../new-utils");
 
 /**
  * Utilities for concurrent query execution with the DataStax Node.js Driver.
@@ -57,7 +59,7 @@ function executeConcurrent(client, query, parameters, options) {
 
     if (typeof query === "string") {
         if (Array.isArray(parameters)) {
-            return new ArrayBasedExecutor(
+            return new PreparedArrayExecutor(
                 client,
                 query,
                 parameters,
@@ -81,7 +83,7 @@ function executeConcurrent(client, query, parameters, options) {
 
     if (Array.isArray(query)) {
         options = parameters;
-        return new ArrayBasedExecutor(client, null, query, options).execute();
+        return new ArrayBasedExecutor(client, query, options).execute();
     }
 
     throw new TypeError(
@@ -90,14 +92,79 @@ function executeConcurrent(client, query, parameters, options) {
 }
 
 /**
- * Wraps the functionality to execute given an Array.
+ * Creates the options of the concurrent execution, that are common for all the executors.
+ * @param {_Client} client
+ * @param {Object} options
+ * @returns {{queryOptions, concurrency: {concurrencyLevel: number, raiseOnFirstError: boolean, collectResults: boolean}}}
  * @ignore
  */
-class ArrayBasedExecutor {
+function getConcurrentOptions(client, options) {
+    return {
+        // Create ExecutionOptions once, to avoid creation of new
+        // rust QueryOptionsWrapper for each of the executed queries.
+        queryOptions: client.createOptions({
+            prepare: true,
+            executionProfile: options.executionProfile,
+        }),
+        concurrency: {
+            concurrencyLevel: options.concurrencyLevel || 100,
+            raiseOnFirstError: options.raiseOnFirstError !== false,
+            collectResults: !!options.collectResults,
+        },
+    };
+}
+
+/**
+ * Executes the query with the executor driven by the Rust driver,
+ * providing the parameter sets to it with `pushAll`.
+ * @param {_Client} client
+ * @param {String} query
+ * @param {Object} queryOptions
+ * @param {{concurrencyLevel: number, raiseOnFirstError: boolean, collectResults: boolean}} concurrency
+ * @param {ResultSetGroup} resultGroup
+ * @param {function(Object): Promise} pushAll
+ * @returns {Promise<ResultSetGroup>}
+ * @ignore
+ */
+async function executeWithRustExecutor(
+    client,
+    query,
+    queryOptions,
+    concurrency,
+    resultGroup,
+    pushAll,
+) {
+    const executor = await client.rustyConcurrentExecutor(
+        query,
+        queryOptions,
+        concurrency,
+    );
+    let result;
+    try {
+        await pushAll(executor);
+    } finally {
+        // Always finish the execution, also when pushing the parameters failed.
+        result = await executor.finish();
+    }
+    return resultGroup.setRustResult(
+        result,
+        concurrency.raiseOnFirstError,
+        queryOptions.getEncodingOptions(),
+    );
+}
+
+/**
+ * Wraps the functionality to execute a single query given an Array of parameters.
+ *
+ * The executions are driven by the Rust driver. The parameters are encoded and passed
+ * to it in chunks, so that only a part of them is kept in the encoded form at any time.
+ * @ignore
+ */
+class PreparedArrayExecutor {
     /**
      * @param {_Client} client
      * @param {String} query
-     * @param {Array<Array>|Array<{query, params}>} parameters
+     * @param {Array<Array>} parameters
      * @param {Object} [options] The execution options.
      * @private
      */
@@ -106,17 +173,65 @@ class ArrayBasedExecutor {
         this._query = query;
         this._parameters = parameters;
         options = options || utils.emptyObject;
+        const { queryOptions, concurrency } = getConcurrentOptions(
+            client,
+            options,
+        );
+        this._queryOptions = queryOptions;
+        this._concurrency = concurrency;
+        this._result = new ResultSetGroup(options);
+    }
+
+    async execute() {
+        if (this._parameters.length === 0) {
+            return this._result;
+        }
+
+        return executeWithRustExecutor(
+            this._client,
+            this._query,
+            this._queryOptions,
+            this._concurrency,
+            this._result,
+            (executor) => this._pushAll(executor),
+        );
+    }
+
+    async _pushAll(executor) {
+        const chunkSize = this._concurrency.concurrencyLevel;
+        for (let i = 0; i < this._parameters.length; i += chunkSize) {
+            const accepted = await executor.push(
+                this._parameters.slice(i, i + chunkSize),
+            );
+            if (!accepted) {
+                // Execution stopped on the first error.
+                return;
+            }
+        }
+    }
+}
+
+/**
+ * Wraps the functionality to execute given an Array of queries and parameters.
+ * @ignore
+ */
+class ArrayBasedExecutor {
+    /**
+     * @param {_Client} client
+     * @param {Array<{query, params}>} queries
+     * @param {Object} [options] The execution options.
+     * @private
+     */
+    constructor(client, queries, options) {
+        this._client = client;
+        this._queries = queries;
+        options = options || utils.emptyObject;
         this._raiseOnFirstError = options.raiseOnFirstError !== false;
         this._concurrencyLevel = Math.min(
             options.concurrencyLevel || 100,
-            this._parameters.length,
+            this._queries.length,
         );
-        // Create ExecutionOptions here, to avoid creation of new
-        // rust QueryOptionsWrapper for each of the executed queries.
-        this._queryOptions = client.createOptions({
-            prepare: true,
-            executionProfile: options.executionProfile,
-        });
+        this._queryOptions = getConcurrentOptions(client, options).queryOptions;
         this._result = new ResultSetGroup(options);
         this._stop = false;
         this._cache = new PreparedCache();
@@ -136,25 +251,18 @@ class ArrayBasedExecutor {
     async _executeOneAtATime(initialIndex, iteration) {
         const index = initialIndex + this._concurrencyLevel * iteration;
 
-        if (index >= this._parameters.length || this._stop) {
+        if (index >= this._queries.length || this._stop) {
             return Promise.resolve();
         }
 
-        const item = this._parameters[index];
-        let query;
-        let params;
-
-        if (this._query === null) {
-            query = item.query;
-            params = item.params;
-        } else {
-            query = this._query;
-            params = item;
-        }
+        const { query, params } = this._queries[index];
 
         let prepared = this._cache.getElement(query);
         if (!prepared) {
-            prepared = await (this._client.prepareQuery(query));
+            prepared = await this._client.prepareQuery(
+                query,
+                this._queryOptions,
+            );
             this._cache.storeElement(query, prepared);
         }
 
@@ -178,6 +286,11 @@ class ArrayBasedExecutor {
 
 /**
  * Wraps the functionality to execute given a Stream.
+ *
+ * The items of the stream are passed to the Rust driver in chunks,
+ * which executes them in the background. The stream is not read while
+ * the Rust driver has no space for more items,
+ * which applies backpressure to the stream.
  * @ignore
  */
 class StreamBasedExecutor {
@@ -193,108 +306,51 @@ class StreamBasedExecutor {
         this._query = query;
         this._stream = stream;
         options = options || utils.emptyObject;
-        this._raiseOnFirstError = options.raiseOnFirstError !== false;
-        this._concurrencyLevel = options.concurrencyLevel || 100;
-        // Create ExecutionOptions here, to avoid creation of new 
-        // rust QueryOptionsWrapper for each of the executed queries.
-        this._queryOptions = client.createOptions({
-            prepare: true,
-            executionProfile: options.executionProfile,
-        });
-        this._inFlight = 0;
-        this._index = 0;
+        const { queryOptions, concurrency } = getConcurrentOptions(
+            client,
+            options,
+        );
+        this._queryOptions = queryOptions;
+        this._concurrency = concurrency;
         this._result = new ResultSetGroup(options);
-        this._resolveCallback = null;
-        this._rejectCallback = null;
-        this._readEnded = false;
     }
 
-    execute() {
-        return new Promise((resolve, reject) => {
-            this._resolveCallback = resolve;
-            this._rejectCallback = reject;
-
-            this._stream
-                .on("data", (params) => this._executeOne(params))
-                .on("error", (err) => this._setReadEnded(err))
-                .on("end", () => this._setReadEnded());
-        });
+    async execute() {
+        return executeWithRustExecutor(
+            this._client,
+            this._query,
+            this._queryOptions,
+            this._concurrency,
+            this._result,
+            (executor) => this._pushAll(executor),
+        );
     }
 
-    async _executeOne(params) {
-        if (!Array.isArray(params)) {
-            return this._setReadEnded(
-                new TypeError(
+    async _pushAll(executor) {
+        let chunk = [];
+        for await (const params of this._stream) {
+            if (!Array.isArray(params)) {
+                throw new TypeError(
                     "Stream should be in objectMode and should emit Array instances",
-                ),
-            );
-        }
-
-        if (this._readEnded) {
-            // Read ended abruptly because of incorrect format or error event being emitted.
-            // We shouldn't consider additional items.
-            return;
-        }
-
-        this._inFlight++;
-        const index = this._index++;
-
-        this._client
-            .execute(this._query, params, this._queryOptions)
-            .then((rs) => {
-                this._result.setResultItem(index, rs);
-                this._inFlight--;
-            })
-            .catch((err) => {
-                this._inFlight--;
-                this._setError(index, err);
-            })
-            .then(() => {;
-                if (this._stream.isPaused()) {
-                    this._stream.resume();
-                }
-
-                if (this._readEnded && this._inFlight === 0) {
-                    // When read ended and there are no more in-flight requests
-                    // We yield the result to the user.
-                    // It could have ended prematurely when there is a read error
-                    // or there was an execution error and raiseOnFirstError is true
-                    // In that case, calling the resolve callback has no effect
-                    this._resolveCallback(this._result);
+                );
+            }
+            chunk.push(params);
+            // Push the items that are already buffered together,
+            // to avoid crossing into the Rust driver for each of them.
+            if (
+                chunk.length >= this._concurrency.concurrencyLevel ||
+                this._stream.readableLength === 0
+            ) {
+                const accepted = await executor.push(chunk);
+                chunk = [];
+                if (!accepted) {
+                    // Execution stopped on the first error.
+                    return;
                 }
-            });
-
-        if (this._inFlight >= this._concurrencyLevel) {
-            this._stream.pause();
-        }
-    }
-
-    /**
-     * Marks the stream read process as ended.
-     * @param {Error} [err] The stream read error.
-     * @private
-     */
-    _setReadEnded(err) {
-        if (!this._readEnded) {
-            this._readEnded = true;
-
-            if (err) {
-                // There was an error while reading from the input stream.
-                // This should be surfaced as a failure
-                this._rejectCallback(err);
-            } else if (this._inFlight === 0) {
-                // Ended signaled and there are no more pending messages.
-                this._resolveCallback(this._result);
             }
         }
-    }
-
-    _setError(index, err) {
-        this._result.setError(index, err);
-
-        if (this._raiseOnFirstError) {
-            this._readEnded = true;
-            this._rejectCallback(err);
+        if (chunk.length > 0) {
+            await executor.push(chunk);
         }
     }
 }
@@ -343,6 +399,40 @@ class ResultSetGroup {
         }
     }
 
+    /**
+     * Internal method to set the result of the execution driven by the Rust driver.
+     * @param {Object} result Result of the concurrent execution, as returned by the Rust driver.
+     * @param {boolean} raiseOnFirstError
+     * @param {ClientOptions.encoding} [encodingOptions] Options used when decoding the results.
+     * @returns {ResultSetGroup}
+     * @ignore
+     */
+    setRustResult(result, raiseOnFirstError, encodingOptions) {
+        if (result.results) {
+            result.results.forEach((item, index) => {
+                if (item) {
+                    this.setResultItem(
+                        index,
+                        new ResultSet(item, undefined, encodingOptions),
+                    );
+                }
+            });
+        } else {
+            this.totalExecuted += result.totalExecuted - result.errors.length;
+        }
+
+        const errors = result.errors.map(({ index, error }) => {
+            const err = mapNapiError(new Error(error));
+            this.setError(index, err);
+            return err;
+        });
+
+        if (raiseOnFirstError && errors.length > 0) {
+            throw errors[0];
+        }
+        return this;
+    }
+
     /**
      * Internal method to set the error of an execution.
      * @ignore
@@ -1,118 +1,326 @@
 "use strict";
 
-const { throwNotSupported } = require("../../new-utils");
+const rust = require("../../../index");
+const { napiErrorHandler } = require("../../new-utils");
 
 /**
- * @deprecated Not supported by the driver. Usage will throw an error.
+ * Search module.
+ *
+ * Contains the classes to represent the values of the `DateRangeType` custom type.
+ * @module datastax/search
+ */
+
+/**
+ * Defines the possible values of the precision of the date range bound.
+ * @type {Object}
+ * @property {Number} year
+ * @property {Number} month
+ * @property {Number} day
+ * @property {Number} hour
+ * @property {Number} minute
+ * @property {Number} second
+ * @property {Number} millisecond
+ * @memberof module:datastax/search
+ */
+const dateRangePrecision = Object.freeze({
+    year: rust.DateRangePrecision.Year,
+    month: rust.DateRangePrecision.Month,
+    day: rust.DateRangePrecision.Day,
+    hour: rust.DateRangePrecision.Hour,
+    minute: rust.DateRangePrecision.Minute,
+    second: rust.DateRangePrecision.Second,
+    millisecond: rust.DateRangePrecision.Millisecond,
+});
+
+const unboundedString = "*";
+
+const parseDateRange = napiErrorHandler((value) =>
+    rust.DateRangeWrapper.fromString(value),
+);
+const deserializeDateRange = napiErrorHandler((buffer) =>
+    rust.DateRangeWrapper.fromBuffer(buffer),
+);
+const parseBound = napiErrorHandler((value) =>
+    rust.DateRangeBoundWrapper.fromString(value),
+);
+const createBound = napiErrorHandler(
+    (timestamp, precision) =>
+        new rust.DateRangeBoundWrapper(timestamp, precision),
+);
+
+/**
+ * Represents a date range, the value of the `'org.apache.cassandra.db.marshal.DateRangeType'` custom type.
+ *
+ * The date range is either a single value (for example `2015-02`), or a range between two bounds,
+ * which includes both of them (for example `[2015-02 TO 2016]`). Any of the bounds can be unbounded.
+ *
+ * Note that the ScyllaDB Rust driver doesn't support the custom types in the metadata of the results
+ * and prepared statements, so values of this type can be neither read from the results nor provided
+ * as parameters of the queries. This class can only be used to work with the values locally,
+ * see the custom types section of the documentation.
+ * @memberof module:datastax/search
  */
 class DateRange {
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Creates a new instance of {@link DateRange} using a lower bound and an optional upper bound.
+     * When the upper bound is not provided, the date range represents a single value.
+     * @param {DateRangeBound} lowerBound The lower bound of the range, or the single value.
+     * @param {DateRangeBound} [upperBound] The upper bound of the range.
      */
-    constructor() {
-        throwNotSupported("DateRange.constructor");
+    constructor(lowerBound, upperBound) {
+        if (!(lowerBound instanceof DateRangeBound)) {
+            throw new TypeError("The lower bound must be a DateRangeBound");
+        }
+        if (upperBound && !(upperBound instanceof DateRangeBound)) {
+            throw new TypeError("The upper bound must be a DateRangeBound");
+        }
+
+        /**
+         * Gets the lower bound of the range, or the single value.
+         * @type {DateRangeBound}
+         */
+        this.lowerBound = lowerBound;
+
+        /**
+         * Gets the upper bound of the range, or `null` when the date range represents a single value.
+         * @type {DateRangeBound|null}
+         */
+        this.upperBound = upperBound || null;
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Returns true when the value of the provided instance is equal to this instance.
+     * @param {DateRange} other
+     * @returns {Boolean}
      */
-    equals() {
-        throwNotSupported("DateRange.equals");
+    equals(other) {
+        if (!(other instanceof DateRange)) {
+            return false;
+        }
+        if (!this.lowerBound.equals(other.lowerBound)) {
+            return false;
+        }
+        if (this.upperBound === null || other.upperBound === null) {
+            return this.upperBound === other.upperBound;
+        }
+        return this.upperBound.equals(other.upperBound);
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Returns the string representation of the instance, for example `[2015-02 TO 2016]`.
+     * @returns {String}
      */
     toString() {
-        throwNotSupported("DateRange.toString");
+        return this.getInternal().toString();
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Returns the serialized value of the `DateRangeType` custom type.
+     * @returns {Buffer}
      */
     toBuffer() {
-        throwNotSupported("DateRange.toBuffer");
+        return this.getInternal().toBuffer();
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Returns the {@link DateRange} representation of the given string.
+     *
+     * The string is either a single value (`2015-02`, `*`), or a range (`[2015-02 TO 2016]`, `[* TO 2016]`).
+     * @param {String} dateRangeString
+     * @returns {DateRange}
      */
-    static fromString() {
-        throwNotSupported("DateRange.fromString");
+    static fromString(dateRangeString) {
+        if (typeof dateRangeString !== "string") {
+            throw new TypeError("The date range must be a string");
+        }
+        return DateRange.fromRust(parseDateRange(dateRangeString));
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Deserializes the value of the `DateRangeType` custom type.
+     * @param {Buffer} buffer
+     * @returns {DateRange}
      */
-    static fromBuffer() {
-        throwNotSupported("DateRange.fromBuffer");
+    static fromBuffer(buffer) {
+        if (!Buffer.isBuffer(buffer)) {
+            throw new TypeError("The date range must be a Buffer");
+        }
+        return DateRange.fromRust(deserializeDateRange(buffer));
     }
 
+    /**
+     * Get DateRange from rust object.
+     * @package
+     * @param {rust.DateRangeWrapper} arg
+     * @returns {DateRange}
+     */
+    static fromRust(arg) {
+        const lowerBound = DateRangeBound.fromRust(arg.getLowerBound());
+        if (!arg.isRange()) {
+            return new DateRange(lowerBound);
+        }
+        return new DateRange(
+            lowerBound,
+            DateRangeBound.fromRust(arg.getUpperBound()),
+        );
+    }
+
+    /**
+     * @package
+     * @returns {rust.DateRangeWrapper}
+     */
+    getInternal() {
+        const lowerBound = this.lowerBound.getInternal();
+        if (this.upperBound === null) {
+            return rust.DateRangeWrapper.single(lowerBound);
+        }
+        return rust.DateRangeWrapper.range(
+            lowerBound,
+            this.upperBound.getInternal(),
+        );
+    }
 }
 
 /**
- * @deprecated Not supported by the driver. Usage will throw an error.
+ * Represents a date range bound, a point in time with the precision.
+ * @memberof module:datastax/search
  */
 class DateRangeBound {
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Creates a new instance of {@link DateRangeBound}.
+     * @param {Date} date The point in time of the bound.
+     * @param {Number} precision The precision of the bound,
+     * one of the values of [dateRangePrecision]{@link module:datastax/search~dateRangePrecision}.
      */
-    constructor() {
-        throwNotSupported("DateRangeBound.constructor");
+    constructor(date, precision) {
+        /**
+         * The point in time of the bound.
+         * @type {Date}
+         */
+        this.date = date;
+
+        /**
+         * The precision of the bound.
+         * @type {Number}
+         */
+        this.precision = precision;
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Returns the string representation of the bound, truncated to the precision,
+     * for example `2015-02-10T10:15Z`, or `*` for the unbounded bound.
+     * @returns {String}
      */
     toString() {
-        throwNotSupported("DateRangeBound.toString");
+        if (this.isUnbounded()) {
+            return unboundedString;
+        }
+        return this.getInternal().toString();
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Returns true when the value of the provided instance is equal to this instance.
+     * @param {DateRangeBound} other
+     * @returns {Boolean}
      */
-    equals() {
-        throwNotSupported("DateRangeBound.equals");
+    equals(other) {
+        if (!(other instanceof DateRangeBound)) {
+            return false;
+        }
+        if (this.isUnbounded() || other.isUnbounded()) {
+            return this.isUnbounded() === other.isUnbounded();
+        }
+        return (
+            this.precision === other.precision &&
+            this.date.getTime() === other.date.getTime()
+        );
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Returns true when the bound is unbounded.
+     * @returns {Boolean}
      */
     isUnbounded() {
-        throwNotSupported("DateRangeBound.isUnbounded");
-    };
+        return this.precision === -1 && this.date === null;
+    }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Parses the bound from the string, with the precision based on the number of provided fields.
+     * @param {String} boundaryString The bound, for example `2015-02-10T10:15Z`, or `*`.
+     * @returns {DateRangeBound}
      */
-    static fromString() {
-        throwNotSupported("DateRangeBound.fromString");
+    static fromString(boundaryString) {
+        if (boundaryString === unboundedString) {
+            return DateRangeBound.unbounded;
+        }
+        if (typeof boundaryString !== "string") {
+            throw new TypeError("The date range bound must be a string");
+        }
+        return DateRangeBound.fromRust(parseBound(boundaryString));
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Converts the bound into the lower bound of a range,
+     * by rounding the date down to the first millisecond of the unit of precision.
+     * @param {DateRangeBound} bound
+     * @returns {DateRangeBound}
      */
-    static unbounded = undefined;
+    static toLowerBound(bound) {
+        if (bound.isUnbounded()) {
+            return bound;
+        }
+        return DateRangeBound.fromRust(bound.getInternal().toLowerBound());
+    }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Converts the bound into the upper bound of a range,
+     * by rounding the date up to the last millisecond of the unit of precision.
+     * @param {DateRangeBound} bound
+     * @returns {DateRangeBound}
      */
-    static toLowerBound() {
-        throwNotSupported("DateRangeBound.toLowerBound");
+    static toUpperBound(bound) {
+        if (bound.isUnbounded()) {
+            return bound;
+        }
+        return DateRangeBound.fromRust(bound.getInternal().toUpperBound());
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Get DateRangeBound from rust object, with `null` representing the unbounded bound.
+     * @package
+     * @param {rust.DateRangeBoundWrapper|null} arg
+     * @returns {DateRangeBound}
      */
-    static toUpperBound() {
-        throwNotSupported("DateRangeBound.toUpperBound");
-    };
+    static fromRust(arg) {
+        if (arg === null) {
+            return DateRangeBound.unbounded;
+        }
+        return new DateRangeBound(new Date(arg.timestamp), arg.precision);
+    }
 
+    /**
+     * @package
+     * @returns {rust.DateRangeBoundWrapper|null}
+     */
+    getInternal() {
+        if (this.isUnbounded()) {
+            return null;
+        }
+        if (!(this.date instanceof Date) || isNaN(this.date.getTime())) {
+            throw new TypeError(
+                "The date of the date range bound must be a valid Date",
+            );
+        }
+        return createBound(this.date.getTime(), this.precision);
+    }
 }
+
 /**
- * Search module is not supported. Any usage will throw an error
- * @module datastax/search
+ * The unbounded bound, represented as `*`.
+ * @type {DateRangeBound}
  */
+DateRangeBound.unbounded = Object.freeze(new DateRangeBound(null, -1));
 
 exports.DateRange = DateRange;
 exports.DateRangeBound = DateRangeBound;
+exports.dateRangePrecision = dateRangePrecision;
@@ -1,4 +1,5 @@
 "use strict";
+const os = require("os");
 const util = require("util");
 
 const types = require("./types");
@@ -13,8 +14,8 @@ const MutableLong = require("./types/mutable-long");
 const utils = require("./utils");
 const token = require("./token");
 const { DateRange } = require("./datastax/search");
+const { LineString, Point, Polygon } = require("./geometry");
 const Vector = require("./types/vector");
-const { throwNotSupported } = require("./new-utils");
 
 // TODO: Those type definitions do not work with jsdoc
 // /**
@@ -105,6 +106,10 @@ const singleFqTypeNamesLength = Object.keys(singleTypeNames).reduce(function (
 const customTypeNames = Object.freeze({
     duration: "org.apache.cassandra.db.marshal.DurationType",
     vector: "org.apache.cassandra.db.marshal.VectorType",
+    dateRange: "org.apache.cassandra.db.marshal.DateRangeType",
+    point: "org.apache.cassandra.db.marshal.PointType",
+    lineString: "org.apache.cassandra.db.marshal.LineStringType",
+    polygon: "org.apache.cassandra.db.marshal.PolygonType",
 });
 
 const nullValueBuffer = utils.allocBufferFromArray([255, 255, 255, 255]);
@@ -189,13 +194,23 @@ class Encoder {
         }
 
         // Map JS TypedArrays onto vectors
-        else if (value instanceof types.Vector) {
+        else if (
+            value instanceof types.Vector ||
+            getVectorTypedArray(value) !== null
+        ) {
             if (value && value.length > 0) {
-                if (value instanceof Float32Array) {
+                const typedArray = getVectorTypedArray(value);
+                if (
+                    typedArray !== null &&
+                    (ArrayBuffer.isView(value) || !value.subtype)
+                ) {
                     return {
                         code: dataTypes.custom,
                         customTypeName: "vector",
-                        info: [{ code: dataTypes.float }, value.length],
+                        info: [
+                            { code: getTypedArrayElementCode(typedArray) },
+                            value.length,
+                        ],
                     };
                 }
 
@@ -228,7 +243,16 @@ class Encoder {
         } else if (Array.isArray(value)) {
             return { code: dataTypes.list };
         } else if (value instanceof DateRange) {
-            throwNotSupported("encoding DateRange");
+            return {
+                code: dataTypes.custom,
+                info: customTypeNames.dateRange,
+            };
+        } else if (value instanceof Point) {
+            return { code: dataTypes.custom, info: customTypeNames.point };
+        } else if (value instanceof LineString) {
+            return { code: dataTypes.custom, info: customTypeNames.lineString };
+        } else if (value instanceof Polygon) {
+            return { code: dataTypes.custom, info: customTypeNames.polygon };
         }
 
         return null;
@@ -378,10 +402,19 @@ function defineInstanceMembers() {
 
     const customDecoders = {
         [customTypeNames.duration]: decodeDuration,
+        [customTypeNames.dateRange]: decodeDateRange,
+        [customTypeNames.point]: (bytes) => Point.fromBuffer(bytes),
+        [customTypeNames.lineString]: (bytes) => LineString.fromBuffer(bytes),
+        [customTypeNames.polygon]: (bytes) => Polygon.fromBuffer(bytes),
     };
 
     const customEncoders = {
         [customTypeNames.duration]: encodeDuration,
+        [customTypeNames.dateRange]: encodeDateRange,
+        [customTypeNames.point]: (value) => encodeGeometry(value, Point),
+        [customTypeNames.lineString]: (value) =>
+            encodeGeometry(value, LineString),
+        [customTypeNames.polygon]: (value) => encodeGeometry(value, Polygon),
     };
 
     // Decoding methods
@@ -1338,7 +1371,14 @@ function defineInstanceMembers() {
      * @returns {Buffer}
      */
     this.encodeVector = function (value, params) {
-        if (!(value instanceof Vector)) {
+        let typedArray = getVectorTypedArray(value);
+        if (
+            typedArray !== null &&
+            getTypedArrayElementCode(typedArray) !== params.info[0].code
+        ) {
+            typedArray = null;
+        }
+        if (typedArray === null && !(value instanceof Vector)) {
             throw new TypeError(
                 "Driver only supports Vector type when encoding a vector",
             );
@@ -1355,6 +1395,11 @@ function defineInstanceMembers() {
             throw new TypeError("Cannot encode empty array as vector");
         }
 
+        if (typedArray !== null) {
+            // Elements matching the subtype are copied at once, without encoding each of them
+            return encodeTypedArray(typedArray);
+        }
+
         const serializationSize = this.serializationSizeIfFixed(params.info[0]);
         const encoded = [];
         for (const elem of value) {
@@ -2228,6 +2273,93 @@ function encodeDuration(value) {
     return value.toBuffer();
 }
 
+function decodeDateRange(bytes) {
+    return DateRange.fromBuffer(bytes);
+}
+
+function encodeDateRange(value) {
+    if (!(value instanceof DateRange)) {
+        throw new TypeError(
+            "Not a valid date range, expected DateRange obtained " +
+                util.inspect(value),
+        );
+    }
+    return value.toBuffer();
+}
+
+function encodeGeometry(value, type) {
+    if (!(value instanceof type)) {
+        throw new TypeError(
+            `Not a valid geometry, expected ${type.name} obtained ` +
+                util.inspect(value),
+        );
+    }
+    return value.toBuffer();
+}
+
+/**
+ * Gets the typed array holding the elements of the given vector value,
+ * or null if the elements are not stored in a typed array supported as a vector.
+ * @param {*} value Typed array or Vector
+ * @returns {Float32Array | Float64Array | Int32Array | null}
+ * @private
+ */
+function getVectorTypedArray(value) {
+    // Float32Array is also considered an instance of Vector
+    const elements =
+        !ArrayBuffer.isView(value) && value instanceof Vector
+            ? value.elements
+            : value;
+    if (
+        elements instanceof Float32Array ||
+        elements instanceof Float64Array ||
+        elements instanceof Int32Array
+    ) {
+        return elements;
+    }
+    return null;
+}
+
+/**
+ * @param {Float32Array | Float64Array | Int32Array} typedArray
+ * @returns {Number} code of the CQL type corresponding to the elements of the typed array
+ * @private
+ */
+function getTypedArrayElementCode(typedArray) {
+    if (typedArray instanceof Float32Array) {
+        return dataTypes.float;
+    }
+    if (typedArray instanceof Float64Array) {
+        return dataTypes.double;
+    }
+    return dataTypes.int;
+}
+
+/**
+ * Encodes the elements of the typed array as a vector of fixed-size elements in big endian
+ * @param {Float32Array | Float64Array | Int32Array} typedArray
+ * @returns {Buffer}
+ * @private
+ */
+function encodeTypedArray(typedArray) {
+    const buffer = utils.allocBufferUnsafe(typedArray.byteLength);
+    buffer.set(
+        new Uint8Array(
+            typedArray.buffer,
+            typedArray.byteOffset,
+            typedArray.byteLength,
+        ),
+    );
+    if (os.endianness() === "LE") {
+        if (typedArray.BYTES_PER_ELEMENT === 8) {
+            buffer.swap64();
+        } else {
+            buffer.swap32();
+        }
+    }
+    return buffer;
+}
+
 /**
  * @param {String} value
  * @param {Number} startIndex
@@ -161,6 +161,37 @@ class BusyConnectionError extends DriverError {
     }
 }
 
+/**
+ * Represents an error that is raised when a request is aborted with the `AbortSignal`
+ * provided in the [query options]{@link QueryOptions}.
+ */
+class AbortError extends DriverError {
+    /**
+     * @param {string} message
+     */
+    constructor(message) {
+        super(message);
+        this.info =
+            "Represents an error that is raised when a request is aborted with the provided AbortSignal.";
+    }
+}
+
+/**
+ * Represents an error that is raised when a request is executed on a client that has been shut down
+ * (or is being shut down) with [Client#shutdown()]{@link Client#shutdown}.
+ */
+class ShutdownError extends DriverError {
+    /**
+     * @param {string} message
+     */
+    constructor(message) {
+        super(message);
+        this.info =
+            "Represents an error that is raised when a request is executed on a client that has been shut down.";
+    }
+}
+
+exports.AbortError = AbortError;
 exports.ArgumentError = ArgumentError;
 exports.AuthenticationError = AuthenticationError;
 exports.BusyConnectionError = BusyConnectionError;
@@ -170,3 +201,4 @@ exports.DriverInternalError = DriverInternalError;
 exports.NoHostAvailableError = NoHostAvailableError;
 exports.NotSupportedError = NotSupportedError;
 exports.ResponseError = ResponseError;
+exports.ShutdownError = ShutdownError;
@@ -5,6 +5,7 @@ const types = require("./types");
 const errors = require("./errors");
 const _rust = require("../index");
 const { queryOptionsIntoWrapper } = require("./query-options");
+const { validateEncodingOptions } = require("./client-options");
 
 const proxyExecuteKey = "ProxyExecute";
 
@@ -78,6 +79,13 @@ class ExecutionOptions {
      */
     getCustomPayload() {}
 
+    /**
+     * Gets the encoding options of the client, overridden by the encoding options provided for the execution.
+     * @abstract
+     * @returns {ClientOptions.encoding}
+     */
+    getEncodingOptions() {}
+
     /**
      * Gets the amount of rows to retrieve per page.
      * @abstract
@@ -92,6 +100,18 @@ class ExecutionOptions {
      */
     getFixedHost() {}
 
+    /**
+     * When a fixed host is set on the query options, it gets the shard of that host that should handle the query.
+     * @returns {Number}
+     */
+    getFixedShard() {}
+
+    /**
+     * Gets the signal used to abort the execution.
+     * @returns {AbortSignal|undefined}
+     */
+    getSignal() {}
+
     /**
      * Gets the type hints for parameters given in the query, ordered as for the parameters.
      * @abstract
@@ -339,6 +359,17 @@ class DefaultExecutionOptions extends ExecutionOptions {
             this._queryOptions.executionProfile,
         );
 
+        if (this._queryOptions.encoding) {
+            this._encodingOptions = {
+                ...client.options.encoding,
+                ...this._queryOptions.encoding,
+            };
+            // Options of the execution may conflict with the ones of the client
+            validateEncodingOptions(this._encodingOptions);
+        } else {
+            this._encodingOptions = client.options.encoding;
+        }
+
         // Build a custom payload object designed for DSE-specific functionality
         this._customPayload = DefaultExecutionOptions.createCustomPayload(
             this._queryOptions,
@@ -415,6 +446,10 @@ class DefaultExecutionOptions extends ExecutionOptions {
         return this._customPayload;
     }
 
+    getEncodingOptions() {
+        return this._encodingOptions;
+    }
+
     getFetchSize() {
         return ifUndefined(
             this._queryOptions.fetchSize,
@@ -426,6 +461,14 @@ class DefaultExecutionOptions extends ExecutionOptions {
         return this._queryOptions.host;
     }
 
+    getFixedShard() {
+        return this._queryOptions.shard;
+    }
+
+    getSignal() {
+        return this._queryOptions.signal;
+    }
+
     getHints() {
         return this._hints;
     }
@@ -0,0 +1,93 @@
+"use strict";
+
+const rust = require("../../index");
+const { napiErrorHandler } = require("../new-utils");
+
+const parseGeometry = napiErrorHandler((value) =>
+    rust.GeometryWrapper.fromString(value),
+);
+const deserializeGeometry = napiErrorHandler((buffer) =>
+    rust.GeometryWrapper.fromBuffer(buffer),
+);
+
+/**
+ * Base class of the geometry types: {@link Point}, {@link LineString} and {@link Polygon}.
+ *
+ * Geometry values are represented with the WKT format in strings (for example `POINT (1 2)`)
+ * and with the WKB format in the serialized values of the custom types.
+ *
+ * Only the WKT and WKB codecs are supported. The ScyllaDB Rust driver doesn't support the custom types
+ * in the metadata of the results and prepared statements, so values of these types can be neither read
+ * from the results nor provided as parameters of the queries,
+ * see the custom types section of the documentation.
+ * @memberof module:geometry
+ * @abstract
+ */
+class Geometry {
+    /**
+     * Returns the WKT representation of the geometry.
+     * @returns {String}
+     */
+    toString() {
+        return this.getInternal().toString();
+    }
+
+    /**
+     * Returns the WKB representation of the geometry.
+     * @returns {Buffer}
+     */
+    toBuffer() {
+        return this.getInternal().toBuffer();
+    }
+
+    /**
+     * @package
+     * @abstract
+     * @returns {rust.GeometryWrapper}
+     */
+    getInternal() {
+        throw new Error("Method getInternal() must be implemented");
+    }
+
+    /**
+     * Parses the WKT representation of the geometry of the kind of this class.
+     * @package
+     * @param {String} textValue
+     * @returns {rust.GeometryWrapper}
+     */
+    static parseInternal(textValue) {
+        if (typeof textValue !== "string") {
+            throw new TypeError("The geometry must be a string");
+        }
+        return this.checkKind(parseGeometry(textValue));
+    }
+
+    /**
+     * Deserializes the WKB representation of the geometry of the kind of this class.
+     * @package
+     * @param {Buffer} buffer
+     * @returns {rust.GeometryWrapper}
+     */
+    static deserializeInternal(buffer) {
+        if (!Buffer.isBuffer(buffer)) {
+            throw new TypeError("The geometry must be a Buffer");
+        }
+        return this.checkKind(deserializeGeometry(buffer));
+    }
+
+    /**
+     * @private
+     * @param {rust.GeometryWrapper} geometry
+     * @returns {rust.GeometryWrapper}
+     */
+    static checkKind(geometry) {
+        if (geometry.kind !== this.kind) {
+            throw new TypeError(
+                `Expected a ${this.name}, obtained ${geometry.toString()}`,
+            );
+        }
+        return geometry;
+    }
+}
+
+module.exports = Geometry;
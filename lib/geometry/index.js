@@ -1,9 +1,13 @@
 "use strict";
 
 /**
+ * Geometry module.
+ *
+ * Contains the classes to represent the values of the `PointType`, `LineStringType` and `PolygonType` custom types.
  * @module geometry
  */
 
+exports.Geometry = require("./geometry");
 exports.LineString = require("./line-string");
 exports.Point = require("./point");
 exports.Polygon = require("./polygon");
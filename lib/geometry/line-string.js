@@ -1,56 +1,110 @@
 "use strict";
 
-const { throwNotSupported } = require("../new-utils");
+const rust = require("../../index");
+const { napiErrorHandler } = require("../new-utils");
+const Geometry = require("./geometry");
+const Point = require("./point");
+const { equalPoints, toCoordinates, toPoints } = require("./utils");
+
+const createLineString = napiErrorHandler((coordinates) =>
+    rust.GeometryWrapper.lineString(coordinates),
+);
 
 /**
- * @deprecated Not supported by the driver. Usage will throw an error.
+ * Represents a sequence of points in a two-dimensional plane, connected with straight lines,
+ * the value of the `'org.apache.cassandra.db.marshal.LineStringType'` custom type.
+ * @memberof module:geometry
+ * @extends {Geometry}
  */
-class LineString {
-    constructor(point) {
-        throwNotSupported("LineString");
+class LineString extends Geometry {
+    /**
+     * Creates a new {@link LineString} instance.
+     * @param {...Point} points A sequence of {@link Point} items as arguments, or a single array of points.
+     * @example
+     * new LineString(new Point(10.99, 20.02), new Point(14, 26), new Point(34, 1.2));
+     * new LineString([new Point(10.99, 20.02), new Point(14, 26)]);
+     */
+    constructor(...points) {
+        super();
+        if (points.length === 1 && Array.isArray(points[0])) {
+            points = points[0];
+        }
+        if (!points.every((point) => point instanceof Point)) {
+            throw new TypeError("The points of the line string must be Points");
+        }
+
+        /**
+         * Returns a frozen Array of points that represent the line.
+         * @type {Array<Point>}
+         */
+        this.points = Object.freeze([...points]);
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Creates a {@link LineString} instance from the WKB representation of a line.
+     * @param {Buffer} buffer
+     * @returns {LineString}
      */
     static fromBuffer(buffer) {
-        throwNotSupported("LineString.fromBuffer");
+        return LineString.fromRust(LineString.deserializeInternal(buffer));
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Creates a {@link LineString} instance from the WKT representation of a line,
+     * for example `LINESTRING (30 10, 10 30, 40 40)`.
+     * @param {String} textValue
+     * @returns {LineString}
      */
     static fromString(textValue) {
-        throwNotSupported("LineString.fromString");
+        return LineString.fromRust(LineString.parseInternal(textValue));
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Returns true if the values of the line strings are the same, otherwise it returns false.
+     * @param {LineString} other
+     * @returns {Boolean}
      */
-    toBuffer() {
-        throwNotSupported("LineString.toBuffer");
+    equals(other) {
+        if (!(other instanceof LineString)) {
+            return false;
+        }
+        return equalPoints(this.points, other.points);
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Returns a GeoJSON representation of the line string.
+     * @returns {Object}
      */
-    equals(other) {
-        throwNotSupported("LineString.equals");
+    toJSON() {
+        return {
+            type: "LineString",
+            coordinates: this.points.map((point) => [point.x, point.y]),
+        };
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Get LineString from rust object.
+     * @package
+     * @param {rust.GeometryWrapper} arg
+     * @returns {LineString}
      */
-    toString() {
-        throwNotSupported("LineString.toString");
+    static fromRust(arg) {
+        return new LineString(toPoints(arg.getCoordinates()[0]));
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * @package
+     * @returns {rust.GeometryWrapper}
      */
-    toJSON() {
-        throwNotSupported("LineString.toJSON");
+    getInternal() {
+        return createLineString(toCoordinates(this.points));
     }
 }
 
+/**
+ * @package
+ * @type {rust.GeometryKind}
+ */
+LineString.kind = rust.GeometryKind.LineString;
+
 module.exports = LineString;
@@ -1,56 +1,110 @@
 "use strict";
 
-const { throwNotSupported } = require("../new-utils");
+const rust = require("../../index");
+const { napiErrorHandler } = require("../new-utils");
+const Geometry = require("./geometry");
+
+const createPoint = napiErrorHandler((x, y) =>
+    rust.GeometryWrapper.point(x, y),
+);
 
 /**
- * @deprecated Not supported by the driver. Usage will throw an error.
+ * Represents a point in a two-dimensional plane,
+ * the value of the `'org.apache.cassandra.db.marshal.PointType'` custom type.
+ * @memberof module:geometry
+ * @extends {Geometry}
  */
-class Point {
+class Point extends Geometry {
+    /**
+     * Creates a new {@link Point} instance.
+     * @param {Number} x The X coordinate.
+     * @param {Number} y The Y coordinate.
+     */
     constructor(x, y) {
-        throwNotSupported("Point");
+        super();
+        if (typeof x !== "number" || typeof y !== "number") {
+            throw new TypeError("X and Y must be numbers");
+        }
+        if (!Number.isFinite(x) || !Number.isFinite(y)) {
+            throw new TypeError("X and Y must be finite numbers");
+        }
+
+        /**
+         * Returns the X coordinate of this 2D point.
+         * @type {Number}
+         */
+        this.x = x;
+
+        /**
+         * Returns the Y coordinate of this 2D point.
+         * @type {Number}
+         */
+        this.y = y;
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Creates a {@link Point} instance from the WKB representation of a 2D point.
+     * @param {Buffer} buffer
+     * @returns {Point}
      */
     static fromBuffer(buffer) {
-        throwNotSupported("Point.fromBuffer");
+        return Point.fromRust(Point.deserializeInternal(buffer));
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Creates a {@link Point} instance from the WKT representation of a 2D point,
+     * for example `POINT (1 2)`.
+     * @param {String} textValue
+     * @returns {Point}
      */
     static fromString(textValue) {
-        throwNotSupported("Point.fromString");
+        return Point.fromRust(Point.parseInternal(textValue));
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Returns true if the values of the point are the same, otherwise it returns false.
+     * @param {Point} other
+     * @returns {Boolean}
      */
-    toBuffer() {
-        throwNotSupported("Point.toBuffer");
+    equals(other) {
+        if (!(other instanceof Point)) {
+            return false;
+        }
+        return this.x === other.x && this.y === other.y;
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Returns a GeoJSON representation of the point.
+     * @returns {Object}
      */
-    equals(other) {
-        throwNotSupported("Point.equals");
+    toJSON() {
+        return { type: "Point", coordinates: [this.x, this.y] };
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Get Point from rust object.
+     * @package
+     * @param {rust.GeometryWrapper} arg
+     * @returns {Point}
      */
-    toString() {
-        throwNotSupported("Point.toString");
+    static fromRust(arg) {
+        const [x, y] = arg.getCoordinates()[0];
+        return new Point(x, y);
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * @package
+     * @returns {rust.GeometryWrapper}
      */
-    toJSON() {
-        throwNotSupported("Point.toJSON");
+    getInternal() {
+        return createPoint(this.x, this.y);
     }
 }
 
+/**
+ * @package
+ * @type {rust.GeometryKind}
+ */
+Point.kind = rust.GeometryKind.Point;
+
 module.exports = Point;
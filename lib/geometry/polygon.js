@@ -1,56 +1,123 @@
 "use strict";
 
-const { throwNotSupported } = require("../new-utils");
+const rust = require("../../index");
+const { napiErrorHandler } = require("../new-utils");
+const Geometry = require("./geometry");
+const Point = require("./point");
+const { equalPoints, toCoordinates, toPoints } = require("./utils");
+
+const createPolygon = napiErrorHandler((rings) =>
+    rust.GeometryWrapper.polygon(rings),
+);
 
 /**
- * @deprecated Not supported by the driver. Usage will throw an error.
+ * Represents a planar surface in a two-dimensional plane, bounded by one exterior ring
+ * and zero or more interior rings (holes),
+ * the value of the `'org.apache.cassandra.db.marshal.PolygonType'` custom type.
+ * @memberof module:geometry
+ * @extends {Geometry}
  */
-class Polygon {
-    constructor(ringPoints) {
-        throwNotSupported("Polygon");
+class Polygon extends Geometry {
+    /**
+     * Creates a new {@link Polygon} instance.
+     * @param {...Array<Point>} ringPoints A sequence of rings as arguments, each of them an Array of points.
+     * The first ring is the exterior ring, the following rings are the interior rings.
+     * @example
+     * new Polygon([new Point(30, 10), new Point(40, 40), new Point(10, 20), new Point(30, 10)]);
+     */
+    constructor(...ringPoints) {
+        super();
+        if (
+            !ringPoints.every(
+                (ring) =>
+                    Array.isArray(ring) &&
+                    ring.every((point) => point instanceof Point),
+            )
+        ) {
+            throw new TypeError(
+                "The rings of the polygon must be Arrays of Points",
+            );
+        }
+
+        /**
+         * Returns a frozen Array of rings, each of them a frozen Array of points.
+         * @type {Array<Array<Point>>}
+         */
+        this.rings = Object.freeze(
+            ringPoints.map((ring) => Object.freeze([...ring])),
+        );
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Creates a {@link Polygon} instance from the WKB representation of a polygon.
+     * @param {Buffer} buffer
+     * @returns {Polygon}
      */
     static fromBuffer(buffer) {
-        throwNotSupported("Polygon.fromBuffer");
+        return Polygon.fromRust(Polygon.deserializeInternal(buffer));
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Creates a {@link Polygon} instance from the WKT representation of a polygon,
+     * for example `POLYGON ((30 10, 40 40, 20 40, 10 20, 30 10))`.
+     * @param {String} textValue
+     * @returns {Polygon}
      */
     static fromString(textValue) {
-        throwNotSupported("Polygon.fromString");
+        return Polygon.fromRust(Polygon.parseInternal(textValue));
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Returns true if the values of the polygons are the same, otherwise it returns false.
+     * @param {Polygon} other
+     * @returns {Boolean}
      */
-    toBuffer() {
-        throwNotSupported("Polygon.toBuffer");
+    equals(other) {
+        if (!(other instanceof Polygon)) {
+            return false;
+        }
+        return (
+            this.rings.length === other.rings.length &&
+            this.rings.every((ring, i) => equalPoints(ring, other.rings[i]))
+        );
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Returns a GeoJSON representation of the polygon.
+     * @returns {Object}
      */
-    equals(other) {
-        throwNotSupported("Polygon.equals");
+    toJSON() {
+        return {
+            type: "Polygon",
+            coordinates: this.rings.map((ring) =>
+                ring.map((point) => [point.x, point.y]),
+            ),
+        };
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * Get Polygon from rust object.
+     * @package
+     * @param {rust.GeometryWrapper} arg
+     * @returns {Polygon}
      */
-    toString() {
-        throwNotSupported("Polygon.toString");
+    static fromRust(arg) {
+        return new Polygon(...arg.getCoordinates().map(toPoints));
     }
 
     /**
-     * @deprecated Not supported by the driver. Usage will throw an error.
+     * @package
+     * @returns {rust.GeometryWrapper}
      */
-    toJSON() {
-        throwNotSupported("Polygon.toJSON");
+    getInternal() {
+        return createPolygon(this.rings.map(toCoordinates));
     }
 }
 
+/**
+ * @package
+ * @type {rust.GeometryKind}
+ */
+Polygon.kind = rust.GeometryKind.Polygon;
+
 module.exports = Polygon;
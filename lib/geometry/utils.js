@@ -0,0 +1,39 @@
+"use strict";
+
+const Point = require("./point");
+
+/**
+ * @param {Array<Point>} points
+ * @param {Array<Point>} other
+ * @returns {Boolean}
+ */
+function equalPoints(points, other) {
+    return (
+        points.length === other.length &&
+        points.every((point, i) => point.equals(other[i]))
+    );
+}
+
+/**
+ * Converts the flattened list of coordinates into points.
+ * @param {Array<Number>} coordinates
+ * @returns {Array<Point>}
+ */
+function toPoints(coordinates) {
+    const points = [];
+    for (let i = 0; i < coordinates.length; i += 2) {
+        points.push(new Point(coordinates[i], coordinates[i + 1]));
+    }
+    return points;
+}
+
+/**
+ * Converts the points into the flattened list of coordinates.
+ * @param {Array<Point>} points
+ * @returns {Array<Number>}
+ */
+function toCoordinates(points) {
+    return points.flatMap((point) => [point.x, point.y]);
+}
+
+module.exports = { equalPoints, toCoordinates, toPoints };
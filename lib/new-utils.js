@@ -23,6 +23,26 @@ const errorTypeMap = {
 
 const concatenationMark = "#";
 
+/**
+ * Maps the napi error to Node.js error or custom error.
+ * Message of the napi error is of format errorType#errorMessage,
+ * if the error is not of this format, the original error is returned.
+ *
+ * @param {Error} error The original error.
+ * @returns {Error} The error of the appropriate type.
+ */
+function mapNapiError(error) {
+    const [errorType, ...messageParts] = error.message.split(concatenationMark);
+    const message = messageParts.join(concatenationMark);
+
+    if (errorTypeMap[errorType]) {
+        const newError = new errorTypeMap[errorType](message);
+        newError.stack = error.stack;
+        return newError;
+    }
+    return error;
+}
+
 /**
  * A wrapper function to map napi errors to Node.js errors or custom errors.
  * Because NAPI-RS does not support throwing errors different that Error, for example
@@ -38,18 +58,7 @@ function napiErrorHandler(fn) {
         try {
             return fn.apply(this, args);
         } catch (error) {
-            // Check if message is of format errorType#errorMessage, if so map it to
-            // appropriate error, otherwise throw the original error.
-            const [errorType, ...messageParts] =
-                error.message.split(concatenationMark);
-            const message = messageParts.join(concatenationMark);
-
-            if (errorTypeMap[errorType]) {
-                const newError = new errorTypeMap[errorType](message);
-                newError.stack = error.stack;
-                throw newError;
-            }
-            throw error;
+            throw mapNapiError(error);
         }
     };
 }
@@ -105,6 +114,7 @@ function arbitraryValueToBigInt(value) {
 }
 
 exports.throwNotSupported = throwNotSupported;
+exports.mapNapiError = mapNapiError;
 exports.napiErrorHandler = napiErrorHandler;
 exports.throwNotSupported = throwNotSupported;
 exports.bigintToLong = bigintToLong;
@@ -109,5 +109,36 @@ class EC2MultiRegionTranslator extends AddressTranslator {
     }
 }
 
+/**
+ * {@link AddressTranslator} implementation that translates the addresses using a static map.
+ *
+ * Useful when the nodes are behind NAT or exposed as separate services (for instance in Kubernetes),
+ * and the addresses of the peers are not reachable from the client.
+ * Addresses not present in the map are not translated.
+ */
+class StaticAddressTranslator extends AddressTranslator {
+    /**
+     * Creates a new instance of the translator.
+     * @param {Object<string, string>} addressMap Map from the node endpoints to the translated endpoints,
+     * both in the format `ipAddress:port` (IPv6 addresses in the format `[ipAddress]:port`).
+     */
+    constructor(addressMap) {
+        super();
+        this.addressMap = Object.assign({}, addressMap);
+    }
+    /**
+     * Translates the endpoint using the address map, leaving it unchanged when it is not present in the map.
+     */
+    translate(address, port, callback) {
+        const endpoint =
+            address.indexOf(":") >= 0
+                ? "[" + address + "]:" + port
+                : address + ":" + port;
+        const translated = this.addressMap[endpoint];
+        callback(translated !== undefined ? translated : endpoint);
+    }
+}
+
 exports.AddressTranslator = AddressTranslator;
 exports.EC2MultiRegionTranslator = EC2MultiRegionTranslator;
+exports.StaticAddressTranslator = StaticAddressTranslator;
@@ -24,6 +24,12 @@ export namespace policies {
     class EC2MultiRegionTranslator implements AddressTranslator {
       translate(address: string, port: number, callback: Function): void;
     }
+
+    class StaticAddressTranslator implements AddressTranslator {
+      constructor(addressMap: { [endpoint: string]: string });
+
+      translate(address: string, port: number, callback: Function): void;
+    }
   }
 
   namespace loadBalancing {
@@ -213,6 +219,10 @@ export namespace policies {
     class MonotonicTimestampGenerator implements TimestampGenerator {
       constructor(warningThreshold: number, minLogInterval: number);
 
+      readonly warningThreshold: number;
+
+      readonly minLogInterval: number;
+
       getDate(): number;
 
       next(client: Client): types.Long | number;
@@ -84,6 +84,20 @@ class MonotonicTimestampGenerator extends TimestampGenerator {
         this._lastDate = 0;
         this._lastLogDate = 0;
     }
+    /**
+     * How far in the future timestamps are allowed to drift before a warning is logged, expressed in milliseconds.
+     * @type {Number}
+     */
+    get warningThreshold() {
+        return this._warningThreshold;
+    }
+    /**
+     * The time separation between log events, expressed in milliseconds. Values under 1 disable logging.
+     * @type {Number}
+     */
+    get minLogInterval() {
+        return this._minLogInterval;
+    }
     /**
      * Returns the current time in milliseconds since UNIX epoch
      * @returns {Number}
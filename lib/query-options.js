@@ -1,5 +1,6 @@
 const Long = require("long");
 const _execOptions = require("./execution-options");
+const errors = require("./errors");
 const { longToBigint } = require("./new-utils");
 const rust = require("../index");
 
@@ -26,7 +27,16 @@ const rust = require("../index");
  * [TODO: Test this field]
  * @property {Object} [customPayload] Key-value payload to be passed to the server. On the Cassandra side,
  * implementations of QueryHandler can use this data.
- * [TODO: Add support for this field]
+ *
+ * Not supported: the underlying ScyllaDB Rust driver can neither send custom payloads with the requests,
+ * nor expose the custom payloads of the responses. The option is ignored.
+ * @property {Object} [encoding] Encoding options for this execution, overriding the
+ * [encoding options of the client]{@link ClientOptions}.
+ *
+ * Only the options used when decoding the results are supported: `map`, `set`, `timestampDecoding`,
+ * `udtConstructors` and `udtFactories`.
+ * For example, `{ encoding: { map: Map, set: Set } }` makes the CQL maps and sets of the result
+ * decoded as the JS `Map` and `Set`. The options are validated in the same way as the options of the client.
  * @property {string|ExecutionProfile} [executionProfile] Name or instance of the [profile]{@link ExecutionProfile} to
  * be used for this execution. If not set, it will the use "default" execution profile.
  * [TODO: Add support for this field]
@@ -58,11 +68,16 @@ const rust = require("../index");
  * [TODO: Add support for this field]
  * @property {string} [keyspace] Specifies the keyspace for the query. It is used for the following:
  *
- * 1. To indicate what keyspace the statement is applicable to (protocol V5+ only).  This is useful when the
+ * 1. To indicate what keyspace the statement is applicable to. This is useful when the
  * query does not provide an explicit keyspace and you want to override the current {@link Client#keyspace}.
  * 2. For query routing when the query operates on a different keyspace than the current {@link Client#keyspace}.
  *
- * [TODO: Add support for this field]
+ * The underlying driver uses protocol V4, which can't send the keyspace together with the request.
+ * Instead, the statements in a keyspace other than the current {@link Client#keyspace} are executed
+ * with a separate set of connections, that use the requested keyspace. The keyspace name is case sensitive.
+ * The connections are opened when the keyspace is requested for the first time, and reused for the following
+ * statements in this keyspace. Connections for at most 8 keyspaces are kept open at the same time:
+ * when another keyspace is requested, the connections of the least recently used keyspace are closed.
  * @property {boolean} [logged] Determines if the batch should be written to the batchlog. Only valid for
  * [Client#batch()]{@link Client#batch}, it will be ignored by other methods. Default: true.
  * [TODO: Add support for this field]
@@ -101,6 +116,13 @@ const rust = require("../index");
  * conditional updates.
  * This option will be ignored for anything else that a conditional update/insert.
  * [TODO: Add support for this field]
+ * @property {number} [shard] The shard of the [host]{@link QueryOptions} that should handle the query.
+ * Only valid together with the `host` option. When not set, the shard is selected by the driver.
+ * When the host doesn't have such shard, the request fails with [ArgumentError]{@link module:errors~ArgumentError}.
+ * @property {AbortSignal} [signal] Signal used to abort the execution. When the signal is aborted, the request
+ * in progress (including the preparation of the statement) is cancelled and it fails with
+ * [AbortError]{@link module:errors~AbortError}.
+ * Results of the already completed requests are not affected.
  * @property {number|Long} [timestamp] The default timestamp for the query in microseconds from the unix epoch
  * (00:00:00, January 1st, 1970).
  *
@@ -128,14 +150,35 @@ function queryOptionsIntoWrapper(options) {
     rustOptions.consistency = options.getConsistency();
     rustOptions.counter = options.counter;
     rustOptions.fetchSize = options.getFetchSize();
+    let host = options.getFixedHost();
+    if (host) {
+        if (!host.hostId) {
+            throw new errors.ArgumentError(
+                "The target host must have a host ID: " + host.address,
+            );
+        }
+        // Host ID is a Buffer for hosts retrieved from the client
+        rustOptions.hostId =
+            host.hostId instanceof Buffer
+                ? host.hostId.toString("hex")
+                : host.hostId.toString();
+    }
     rustOptions.isIdempotent = options.isIdempotent();
-    rustOptions.keyspace = options.keyspace;
+    rustOptions.keyspace = options.getKeyspace();
     rustOptions.logged = options.logged;
     rustOptions.prepare = options.prepare;
     rustOptions.readTimeout = options.getReadTimeout();
     rustOptions.routingIndexes = options.getRoutingIndexes();
     rustOptions.routingNames = options.getRoutingNames();
     rustOptions.serialConsistency = options.getSerialConsistency();
+    let shard = options.getFixedShard();
+    if (shard === null) shard = undefined;
+    if (shard !== undefined && !(Number.isInteger(shard) && shard >= 0)) {
+        throw new errors.ArgumentError(
+            "The shard must be a non-negative integer: " + shard,
+        );
+    }
+    rustOptions.shard = shard;
     let timestamp = options.getTimestamp();
     if (timestamp instanceof Long) timestamp = longToBigint(timestamp);
     else if (timestamp) timestamp = BigInt(timestamp);
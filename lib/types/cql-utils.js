@@ -57,6 +57,7 @@ function convertComplexType(type) {
                 break;
             case rust.CqlType.UserDefinedType:
                 data.info = {
+                    keyspace: type.keyspace,
                     name: type.name,
                     fields: type.udt_types.map((typ, index) => {
                         let obj = { type: convertComplexType(typ) };
@@ -308,10 +308,12 @@ export namespace types {
   interface ResultSet extends Iterable<Row>, AsyncIterable<Row> {
     info: {
       queriedHost: string;
-      triedHosts: { [key: string]: any };
+      queriedHostId: string;
+      triedHosts: { [key: string]: number };
       speculativeExecutions: number;
-      achievedConsistency: consistencies;
-      traceId: Uuid;
+      achievedConsistency: consistencies | null;
+      latency: number | null;
+      traceId: Uuid | null;
       warnings: string[];
       customPayload: any;
     };
@@ -325,6 +327,12 @@ export namespace types {
     first(): Row;
 
     wasApplied(): boolean;
+
+    getExistingRow(): Row | null;
+
+    toArrowIpc(): Buffer | null;
+
+    arrowIpcPages(): AsyncIterableIterator<Buffer>;
   }
 
   interface ResultStream extends stream.Readable {
@@ -422,8 +430,11 @@ export namespace types {
 
   class Vector {
     static get [Symbol.species](): typeof Vector;
-    constructor(elements: Float32Array | Array<any>, subtype?: string);
-    elements: any[];
+    constructor(
+      elements: Float32Array | Float64Array | Int32Array | Array<any>,
+      subtype?: string,
+    );
+    elements: Float32Array | Float64Array | Int32Array | any[];
     length: number;
     subtype: string | undefined;
 
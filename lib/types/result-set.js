@@ -32,8 +32,9 @@ class ResultSet {
     /**
      * @param {rust.QueryResultWrapper} result
      * @param {rust.PagingStateResponseWrapper} [pagingState]
+     * @param {ClientOptions.encoding} [encodingOptions] Options used when decoding the rows.
      */
-    constructor(result, pagingState) {
+    constructor(result, pagingState, encodingOptions) {
         // Old constructor logic only for purpose of unit tests.
         if (!(result instanceof rust.QueryResultWrapper)) {
             console.warn(
@@ -70,7 +71,10 @@ class ResultSet {
          * following pages of results.
          * @type {Array<Row>|undefined}
          */
-        this.rows = resultsWrapper.getRowsFromResultsWrapper(result);
+        this.rows = resultsWrapper.getRowsFromResultsWrapper(
+            result,
+            encodingOptions,
+        );
 
         /**
          * Gets the row length of the result, regardless if the result has been buffered or not
@@ -100,18 +104,28 @@ class ResultSet {
             });
         }
 
-        let traceId = result.getTraceId();
-        if (traceId !== null) traceId = Uuid.fromRust(traceId);
+        const info = result.getInfo();
+        const triedHosts = {};
+        for (const { address, attempts } of info.triedHosts) {
+            triedHosts[address] = attempts;
+        }
 
         /**
          * Information on the execution of a successful query:
          * @member {Object}
          * @property {Number} achievedConsistency The consistency level that has been actually achieved by the query.
-         * @property {String} queriedHost The Cassandra host that coordinated this query.
-         * @property {Object} triedHosts Gets the associative array of host that were queried before getting a valid response,
-         * being the last host the one that replied correctly.
-         * @property {Object} speculativeExecutions The number of speculative executions (not including the first) executed before
-         * getting a valid response.
+         * It's the consistency level of the query (or the default consistency level `localQuorum`, when the query
+         * doesn't have one), unless the retry policy changed it when retrying.
+         * @property {String} queriedHost The address of the host that coordinated this query.
+         * @property {String} queriedHostId The host id of the host that coordinated this query.
+         * @property {Object} triedHosts Gets the associative array of the addresses of the hosts that were queried
+         * before getting a valid response, with the number of attempts sent to each of them.
+         * The addresses are the ones of the connections used for the attempts,
+         * so they may use the shard-aware port.
+         * @property {Number} speculativeExecutions The number of speculative executions (not including the first)
+         * executed before getting a valid response.
+         * @property {Number} latency The time in milliseconds between sending the request and receiving the response,
+         * including the retries and speculative executions.
          * @property {Uuid} traceId Identifier of the trace session.
          * @property {Array.<string>} warnings Warning messages generated by the server when executing the query.
          * @property {Boolean} isSchemaInAgreement Whether the cluster had reached schema agreement after the execution of
@@ -129,13 +143,15 @@ class ResultSet {
          * perform the check manually.
          */
         this.info = {
-            queriedHost: undefined, // Not yet supported by rust driver: https://github.com/scylladb/scylla-rust-driver/issues/1030
-            triedHosts: undefined, // FIXME: Fill this field
-            speculativeExecutions: undefined, // FIXME: Fill this field: https://github.com/scylladb-zpp-2024-javascript-driver/scylladb-javascript-driver/pull/37#discussion_r1817998702
-            achievedConsistency: undefined, // FIXME: Find out more about this field: https://github.com/scylladb-zpp-2024-javascript-driver/scylladb-javascript-driver/pull/37#discussion_r1818000903
-            traceId: traceId,
-            warnings: result.getWarnings(),
-            customPayload: null, // Not exposed by the rust driver: https://github.com/scylladb-zpp-2024-javascript-driver/scylladb-javascript-driver/pull/37#discussion_r1817998912
+            queriedHost: info.queriedHost,
+            queriedHostId: info.queriedHostId,
+            triedHosts: triedHosts,
+            speculativeExecutions: info.speculativeExecutions,
+            achievedConsistency: info.achievedConsistency,
+            latency: info.latency,
+            traceId: info.traceId === null ? null : Uuid.fromRust(info.traceId),
+            warnings: info.warnings,
+            customPayload: null, // Not exposed by the rust driver (neither for requests nor responses): https://github.com/scylladb-zpp-2024-javascript-driver/scylladb-javascript-driver/pull/37#discussion_r1817998912
             isSchemaInAgreement: false, // FIXME: Look into this field: https://github.com/scylladb-zpp-2024-javascript-driver/scylladb-javascript-driver/pull/37#discussion_r1818002641
         };
 
@@ -192,6 +208,9 @@ class ResultSet {
      * information whether it was applied or not.
      */
     wasApplied() {
+        if (this.#rustResult) {
+            return this.#rustResult.wasApplied();
+        }
         if (!this.rows || this.rows.length === 0) {
             return true;
         }
@@ -200,6 +219,18 @@ class ResultSet {
         return typeof applied === "boolean" ? applied : true;
     }
 
+    /**
+     * When this instance is the result of a conditional update query that was not applied,
+     * it returns the row with the existing values, that caused the condition to fail.
+     * The row contains the `[applied]` column, followed by the existing values of the row.
+     *
+     * Otherwise, it returns `null`.
+     * @returns {Row|null}
+     */
+    getExistingRow() {
+        return this.wasApplied() ? null : this.first();
+    }
+
     /**
      * Gets the iterator function.
      *
@@ -281,6 +312,74 @@ class ResultSet {
         };
     }
 
+    /**
+     * Gets the rows of this result set as [Apache Arrow]{@link https://arrow.apache.org/} IPC stream,
+     * with the schema followed by a single record batch, without creating the row objects.
+     *
+     * When the result is paged, the stream contains only the rows of the current page.
+     * To get the rows of all the pages, use {@link module:types~ResultSet#arrowIpcPages}.
+     * @returns {Buffer|null} The IPC stream, or `null` when the query does not return rows.
+     */
+    toArrowIpc() {
+        if (!this.#rustResult) {
+            throw new errors.NotSupportedError(
+                "Arrow export is not supported for the legacy ResultSet",
+            );
+        }
+        return this.#rustResult.toArrowIpc();
+    }
+
+    /**
+     * Gets the async iterator over the parts of [Apache Arrow]{@link https://arrow.apache.org/} IPC stream,
+     * with the rows of all the pages of the result. The driver will fetch the following result pages.
+     *
+     * Each part contains the rows of a single page, as a record batch. The first part starts with the schema,
+     * and the last one ends with the end of stream marker, so the parts concatenated together form a single
+     * IPC stream. The iterator can be passed directly to readers accepting async iterables of bytes,
+     * like `RecordBatchReader.from()` of Arrow JS.
+     *
+     * Multiple concurrent async iterations are not supported.
+     * @example <caption>Reading the result with Arrow JS</caption>
+     * const result = await client.execute(query, params, { prepare: true });
+     * const reader = await RecordBatchReader.from(result.arrowIpcPages());
+     * for await (const batch of reader) {
+     *   console.log(batch.numRows);
+     * }
+     * @returns {AsyncIterableIterator<Buffer>}
+     */
+    async *arrowIpcPages() {
+        if (!this.#rustResult) {
+            throw new errors.NotSupportedError(
+                "Arrow export is not supported for the legacy ResultSet",
+            );
+        }
+        let resultSet = this;
+        let includeSchema = true;
+        while (resultSet) {
+            const pageState = resultSet.rawPageState;
+            const part = resultSet.#rustResult.toArrowIpcPage(
+                includeSchema,
+                !pageState,
+            );
+            if (part === null) {
+                // The query does not return rows
+                return;
+            }
+            yield part;
+
+            resultSet = null;
+            if (pageState) {
+                if (!this.nextPageAsync) {
+                    throw new errors.DriverInternalError(
+                        "Property nextPageAsync should be set when pageState is defined",
+                    );
+                }
+                resultSet = await this.nextPageAsync(pageState);
+                includeSchema = false;
+            }
+        }
+    }
+
     /**
      * Determines whether there are more pages of results.
      * If so, the driver will initially retrieve and contain only the first page of results.
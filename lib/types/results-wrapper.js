@@ -15,14 +15,26 @@ const { convertComplexType } = require("./cql-utils");
 const Vector = require("./vector");
 const { getDataTypeNameByCode } = require(".");
 
+/**
+ * Representations of the timestamps in Rust, by the values of the `encoding.timestampDecoding` option
+ */
+const timestampDecodings = Object.freeze({
+    date: rust.TimestampDecoding.Date,
+    number: rust.TimestampDecoding.Number,
+    bigint: rust.TimestampDecoding.BigInt,
+});
+
 /**
  * Maps the value returned from the Rust driver into expected JS object, based on the provided type information.
  *
  * @param {rust.CqlValueWrapper} field
  * @param {{code: number, info: *|Object}} typ
+ * @param {ClientOptions.encoding} [encodingOptions] Options used when decoding the value:
+ * maps and sets are created with the `map` and `set` constructors, and values of the user defined types
+ * with the `udtFactories`, when provided.
  * @returns {any}
  */
-function getCqlObject(field, typ) {
+function getCqlObject(field, typ, encodingOptions) {
     switch (true) {
         case field === null:
             return null;
@@ -52,22 +64,27 @@ function getCqlObject(field, typ) {
         case rust.CqlType.BigInt:
             return bigintToLong(value);
         case rust.CqlType.Counter:
+        case rust.CqlType.Timestamp:
+            // Timestamps are created in Rust, in the representation selected with the encoding options
             return value;
         case rust.CqlType.Decimal:
             return BigDecimal.fromBuffer(value);
-        case rust.CqlType.Timestamp:
-            // Currently only values inside Date safe range are supported
-            // https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date#the_epoch_timestamps_and_invalid_date
-            // The same problem exists in Datastax driver. This probably should be fixed at some point,
-            // but it's unlikely someone will need timestamps almost 300.000 years into the future.
-            return new Date(Number(value));
         case rust.CqlType.Map:
+            if (encodingOptions && encodingOptions.map) {
+                const mapConstructor = encodingOptions.map;
+                res = new mapConstructor();
+                for (const [key, elem] of value) {
+                    res.set(
+                        getCqlObject(key, typ.info[0], encodingOptions),
+                        getCqlObject(elem, typ.info[1], encodingOptions),
+                    );
+                }
+                return res;
+            }
             res = {};
-            for (const keyValuePair of value) {
-                res[getCqlObject(keyValuePair[0], typ.info[0])] = getCqlObject(
-                    keyValuePair[1],
-                    typ.info[1],
-                );
+            for (const [key, elem] of value) {
+                res[getCqlObject(key, typ.info[0], encodingOptions)] =
+                    getCqlObject(elem, typ.info[1], encodingOptions);
             }
             return res;
         case rust.CqlType.Timeuuid:
@@ -77,33 +94,63 @@ function getCqlObject(field, typ) {
                 value.map((element, index) =>
                     element === null
                         ? undefined
-                        : getCqlObject(element, typ.info[index]),
+                        : getCqlObject(
+                              element,
+                              typ.info[index],
+                              encodingOptions,
+                          ),
                 ),
             );
         case rust.CqlType.Uuid:
             return Uuid.fromRust(value);
         case rust.CqlType.Set:
+            res = value.map((v) => getCqlObject(v, typ.info, encodingOptions));
+            if (encodingOptions && encodingOptions.set) {
+                const setConstructor = encodingOptions.set;
+                return new setConstructor(res);
+            }
+            return res;
         case rust.CqlType.List:
-            return value.map((v) => getCqlObject(v, typ.info));
+            return value.map((v) =>
+                getCqlObject(v, typ.info, encodingOptions),
+            );
         case rust.CqlType.UserDefinedType:
-            // Considering an Object is just a dictionary, we map here from a Dict<Key, Value> to Dict<Key, getCqlObject(Value)>
-            // Dictionary elements are not yet converted values returned from Rust,
-            // so we need to recursively convert those elements into expected types
-            return Object.fromEntries(
-                Object.entries(value).map(([key, elem], index) => {
-                    return [
-                        key,
-                        getCqlObject(elem, typ.info.fields[index].type),
+            // The object is created in Rust, with the registered constructor of the type if any,
+            // so the fields are converted into expected types in place, to keep the object itself
+            for (const field of typ.info.fields) {
+                if (!isReturnedAsIs(field.type)) {
+                    value[field.name] = getCqlObject(
+                        value[field.name],
+                        field.type,
+                        encodingOptions,
+                    );
+                }
+            }
+            if (encodingOptions && encodingOptions.udtFactories) {
+                const factory =
+                    encodingOptions.udtFactories[
+                        `${typ.info.keyspace}.${typ.info.name}`
                     ];
-                }),
-            );
+                if (factory) {
+                    // Factories are called with the fields already converted
+                    return factory(value);
+                }
+            }
+            return value;
         case rust.CqlType.Varint:
             return value;
         case rust.CqlType.Custom:
             if (typ.customTypeName === "vector") {
+                const subtype = getDataTypeNameByCode(typ.info[0]);
+                // Vectors of fixed-size numeric types are already decoded into typed arrays
+                if (ArrayBuffer.isView(value)) {
+                    return new Vector(value, subtype);
+                }
                 return new Vector(
-                    value.map((v) => getCqlObject(v, typ.info[0])),
-                    getDataTypeNameByCode(typ.info[0]),
+                    value.map((v) =>
+                        getCqlObject(v, typ.info[0], encodingOptions),
+                    ),
+                    subtype,
                 );
             }
             throw new Error(`Unexpected custom type (${typ.customTypeName})`);
@@ -112,31 +159,77 @@ function getCqlObject(field, typ) {
     }
 }
 
+/**
+ * Checks if the values of the given type are returned from the Rust driver
+ * in the same representation, as the one expected by the user.
+ * @param {{code: number, info: *|Object}} typ
+ * @returns {boolean}
+ */
+function isReturnedAsIs(typ) {
+    switch (typ.code) {
+        case rust.CqlType.Int:
+        case rust.CqlType.SmallInt:
+        case rust.CqlType.TinyInt:
+        case rust.CqlType.Text:
+        case rust.CqlType.Varchar:
+        case rust.CqlType.Blob:
+        case rust.CqlType.Boolean:
+        case rust.CqlType.Ascii:
+        case rust.CqlType.Double:
+        case rust.CqlType.Float:
+        case rust.CqlType.Counter:
+        case rust.CqlType.Timestamp:
+        case rust.CqlType.Varint:
+            return true;
+        default:
+            return false;
+    }
+}
+
 /**
  * Simple way of getting results from rust driver.
- * Call the driver O(columns * rows) times
+ *
+ * Rows are created in the Rust driver as Row objects, with the values set under the column names.
+ * Only the values of the columns, whose types need a conversion, are replaced here.
  * @param {rust.QueryResultWrapper} result
+ * @param {ClientOptions.encoding} [encodingOptions] Options used when decoding the values
  * @returns {Array<Row> | undefined} Returns array of rows if ResultWrapper has any, and undefined otherwise
  */
-function getRowsFromResultsWrapper(result) {
-    let rustRows = result.getRows();
-    if (rustRows == null) {
+function getRowsFromResultsWrapper(result, encodingOptions) {
+    let rows = result.getRowsAsObjects(
+        Row,
+        encodingOptions ? encodingOptions.udtConstructors : undefined,
+        encodingOptions && encodingOptions.timestampDecoding
+            ? timestampDecodings[encodingOptions.timestampDecoding]
+            : undefined,
+    );
+    if (rows == null) {
         // Empty results are treated as undefined
         return undefined;
     }
 
     let colNames = result.getColumnsNames();
     let types = result.getColumnsTypes().map((typ) => convertComplexType(typ));
-    let rows = [];
+    // Driver returns column names and metadata in the same order.
+    let convertedColumns = [];
+    for (let j = 0; j < types.length; j++) {
+        if (!isReturnedAsIs(types[j])) {
+            convertedColumns.push(j);
+        }
+    }
 
-    for (let i = 0; i < rustRows.length; i++) {
-        let cols = rustRows[i];
-        let collectedRow = new Row(colNames);
-        for (let j = 0; j < cols.length; j++) {
-            // Driver returns column names, metadata and values in the same order.
-            collectedRow[colNames[j]] = getCqlObject(cols[j], types[j]);
+    if (convertedColumns.length === 0) {
+        return rows;
+    }
+    for (let i = 0; i < rows.length; i++) {
+        let row = rows[i];
+        for (const j of convertedColumns) {
+            row[colNames[j]] = getCqlObject(
+                row[colNames[j]],
+                types[j],
+                encodingOptions,
+            );
         }
-        rows.push(collectedRow);
     }
 
     return rows;
@@ -5,17 +5,21 @@ const util = require("node:util");
 class Vector {
     /**
      *
-     * @param {Float32Array | Array<any>} elements
+     * @param {Float32Array | Float64Array | Int32Array | Array<any>} elements
+     * Typed arrays are used as the elements directly, without copying them.
      * @param {string} [subtype]
      */
     constructor(elements, subtype) {
-        if (elements instanceof Float32Array) {
-            this.elements = Array.from(elements);
-        } else if (Array.isArray(elements)) {
+        if (
+            elements instanceof Float32Array ||
+            elements instanceof Float64Array ||
+            elements instanceof Int32Array ||
+            Array.isArray(elements)
+        ) {
             this.elements = elements;
         } else {
             throw new TypeError(
-                "Vector must be constructed with a Float32Array or an Array",
+                "Vector must be constructed with a Float32Array, Float64Array, Int32Array or an Array",
             );
         }
         if (this.elements.length === 0) {
@@ -24,6 +24,7 @@ export type EmptyCallback = (err: Error) => void;
 export type ArrayOrObject = any[] | { [key: string]: any };
 
 export class Client extends events.EventEmitter {
+  configuredCompression: "lz4" | "snappy" | undefined;
   hosts: HostMap;
   keyspace: string;
   metadata: metadata.Metadata;
@@ -106,6 +107,8 @@ export class Client extends events.EventEmitter {
   getReplicas(keyspace: string, token: Buffer): Host[];
 
   getState(): metadata.ClientState;
+
+  getReconnectionMetrics(): { failedAttempts: number; reconnections: number };
 }
 
 export interface HostMap extends events.EventEmitter {
@@ -183,6 +186,7 @@ export interface ExecutionOptions {
 
 export interface ClientOptions {
   contactPoints?: string[];
+  compression?: "lz4" | "snappy";
   localDataCenter?: string;
   keyspace?: string;
   authProvider?: auth.AuthProvider;
@@ -198,6 +202,9 @@ export interface ClientOptions {
     useUndefinedAsUnset?: boolean;
     useBigIntAsLong?: boolean;
     useBigIntAsVarint?: boolean;
+    timestampDecoding?: "date" | "number" | "bigint";
+    udtConstructors?: { [udtName: string]: Function };
+    udtFactories?: { [udtName: string]: Function };
   };
   isMetadataSyncEnabled?: boolean;
   maxPrepared?: number;
@@ -212,8 +219,12 @@ export interface ClientOptions {
   };
   pooling?: {
     coreConnectionsPerHost?: { [key: number]: number };
+    coreConnectionsPerShard?: number;
+    disallowShardAwarePort?: boolean;
     heartBeatInterval?: number;
+    heartBeatTimeout?: number;
     maxRequestsPerConnection?: number;
+    shardAwareLocalPortRange?: { min: number; max: number };
     warmup?: boolean;
   };
   prepareOnAllHosts?: boolean;
@@ -231,6 +242,7 @@ export interface ClientOptions {
   refreshSchemaDelay?: number;
   rePrepareOnUp?: boolean;
   requestTracker?: tracker.RequestTracker;
+  shutdownTimeout?: number;
   socketOptions?: {
     coalescingThreshold?: number;
     connectTimeout?: number;
@@ -249,6 +261,13 @@ export interface QueryOptions {
   consistency?: number;
   counter?: boolean;
   customPayload?: any;
+  encoding?: {
+    map?: Function;
+    set?: Function;
+    timestampDecoding?: "date" | "number" | "bigint";
+    udtConstructors?: { [udtName: string]: Function };
+    udtFactories?: { [udtName: string]: Function };
+  };
   executionProfile?: string | ExecutionProfile;
   fetchSize?: number;
   hints?: string[] | string[][];
@@ -264,6 +283,8 @@ export interface QueryOptions {
   routingKey?: Buffer | Buffer[];
   routingNames?: string[];
   serialConsistency?: number;
+  shard?: number;
+  signal?: AbortSignal;
   timestamp?: number | Long;
   traceQuery?: boolean;
 }
@@ -296,6 +317,10 @@ export class ExecutionProfile {
 }
 
 export namespace errors {
+  class AbortError extends DriverError {
+    constructor(message: string);
+  }
+
   class ArgumentError extends DriverError {
     constructor(message: string);
   }
@@ -343,6 +368,10 @@ export namespace errors {
 
     constructor(code: number, message: string);
   }
+
+  class ShutdownError extends DriverError {
+    constructor(message: string);
+  }
 }
 
 export namespace token {
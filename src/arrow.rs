@@ -0,0 +1,495 @@
+use std::sync::Arc;
+
+use arrow_array::{
+    RecordBatch,
+    builder::{
+        ArrayBuilder, BinaryBuilder, BooleanBuilder, Date32Builder, FixedSizeBinaryBuilder,
+        FixedSizeListBuilder, Float32Builder, Float64Builder, Int8Builder, Int16Builder,
+        Int32Builder, Int64Builder, IntervalMonthDayNanoBuilder, ListBuilder, MapBuilder,
+        StringBuilder, StructBuilder, Time64NanosecondBuilder, TimestampMillisecondBuilder,
+        make_builder,
+    },
+};
+use arrow_buffer::IntervalMonthDayNano;
+use arrow_ipc::writer::{DictionaryTracker, IpcDataGenerator, IpcWriteOptions, write_message};
+use arrow_schema::{ArrowError, DataType, Field, Fields, IntervalUnit, Schema, TimeUnit};
+use napi::Result;
+use scylla::{
+    deserialize::{
+        FrameSlice,
+        value::{
+            DeserializeValue, FrameSliceWithMetadata, ListlikeIterator, MapIterator, UdtIterator,
+            VectorIterator,
+        },
+    },
+    frame::response::result::{CollectionType, ColumnType, NativeType},
+    response::query_result::QueryRowsResult,
+    value::{
+        Counter, CqlDate, CqlDecimalBorrowed, CqlDuration, CqlTime, CqlTimestamp, CqlTimeuuid,
+        CqlVarintBorrowed,
+    },
+};
+use std::net::IpAddr;
+
+use crate::{
+    errors::{ErrorType, err_to_napi, js_typed_error},
+    result::{column_rows, deserialize, ensure_not_empty},
+};
+
+/// Marks the end of the Arrow IPC stream: continuation marker followed by zero length of the message
+const END_OF_STREAM: [u8; 8] = [0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0];
+
+/// Number of days between 0000-01-01 (CQL date 0) and the Unix epoch (CQL date 2^31)
+const UNIX_EPOCH_DATE: i64 = 1 << 31;
+
+/// Converts the rows of the query result into a single Arrow record batch,
+/// with one column for each column of the result.
+pub(crate) fn to_record_batch(result: &QueryRowsResult) -> Result<RecordBatch> {
+    let fields = result
+        .column_specs()
+        .iter()
+        .map(|spec| Ok(Field::new(spec.name(), arrow_type(spec.typ())?, true)))
+        .collect::<Result<Vec<_>>>()?;
+    let schema = Arc::new(Schema::new(fields));
+
+    let rows = column_rows(result)?;
+    let mut builders = schema
+        .fields()
+        .iter()
+        .map(|field| make_builder(field.data_type(), rows.rows_remaining()))
+        .collect::<Vec<_>>();
+    for row in rows {
+        let row = row.map_err(err_to_napi)?;
+        for (column, builder) in row.zip(builders.iter_mut()) {
+            let column = column.map_err(err_to_napi)?;
+            append_value(builder.as_mut(), column.spec.typ(), column.slice)?;
+        }
+    }
+
+    RecordBatch::try_new(
+        schema,
+        builders
+            .iter_mut()
+            .map(|builder| builder.finish())
+            .collect(),
+    )
+    .map_err(err_to_napi)
+}
+
+/// Encodes the record batch as a part of the Arrow IPC stream.
+///
+/// The schema message is written before the record batch only when `include_schema` is set,
+/// and the end of stream marker after it only when `end_of_stream` is set.
+/// This way the parts created for each page of the result can be concatenated into a single stream.
+pub(crate) fn write_ipc(
+    batch: &RecordBatch,
+    include_schema: bool,
+    end_of_stream: bool,
+) -> std::result::Result<Vec<u8>, ArrowError> {
+    let options = IpcWriteOptions::default();
+    let generator = IpcDataGenerator::default();
+    let mut dictionary_tracker = DictionaryTracker::new(false);
+    let mut buffer = vec![];
+
+    if include_schema {
+        let schema = generator.schema_to_bytes_with_dictionary_tracker(
+            &batch.schema(),
+            &mut dictionary_tracker,
+            &options,
+        );
+        write_message(&mut buffer, schema, &options)?;
+    }
+    let (dictionaries, batch) =
+        generator.encoded_batch(batch, &mut dictionary_tracker, &options)?;
+    for message in dictionaries.into_iter().chain([batch]) {
+        write_message(&mut buffer, message, &options)?;
+    }
+    if end_of_stream {
+        buffer.extend_from_slice(&END_OF_STREAM);
+    }
+    Ok(buffer)
+}
+
+/// Maps the CQL type into the Arrow type used to represent its values.
+///
+/// Types without exact Arrow counterpart (decimal, varint and inet) are represented as strings.
+pub(crate) fn arrow_type(typ: &ColumnType) -> Result<DataType> {
+    Ok(match typ {
+        ColumnType::Native(native_type) => match native_type {
+            NativeType::Ascii | NativeType::Text => DataType::Utf8,
+            NativeType::Boolean => DataType::Boolean,
+            NativeType::Blob => DataType::Binary,
+            NativeType::Counter | NativeType::BigInt => DataType::Int64,
+            NativeType::Date => DataType::Date32,
+            NativeType::Decimal | NativeType::Varint | NativeType::Inet => DataType::Utf8,
+            NativeType::Double => DataType::Float64,
+            NativeType::Duration => DataType::Interval(IntervalUnit::MonthDayNano),
+            NativeType::Float => DataType::Float32,
+            NativeType::Int => DataType::Int32,
+            NativeType::SmallInt => DataType::Int16,
+            NativeType::TinyInt => DataType::Int8,
+            NativeType::Time => DataType::Time64(TimeUnit::Nanosecond),
+            NativeType::Timestamp => DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+            NativeType::Timeuuid | NativeType::Uuid => DataType::FixedSizeBinary(16),
+            other => {
+                return Err(js_typed_error(
+                    format!("Missing Arrow representation for CQL native type {other:?}"),
+                    ErrorType::NotSupportedError,
+                ));
+            }
+        },
+        ColumnType::Collection {
+            typ: CollectionType::List(element_type) | CollectionType::Set(element_type),
+            ..
+        } => DataType::List(Arc::new(Field::new_list_field(
+            arrow_type(element_type)?,
+            true,
+        ))),
+        ColumnType::Collection {
+            typ: CollectionType::Map(key_type, value_type),
+            ..
+        } => DataType::Map(
+            Arc::new(Field::new(
+                "entries",
+                DataType::Struct(Fields::from(vec![
+                    Field::new("keys", arrow_type(key_type)?, false),
+                    Field::new("values", arrow_type(value_type)?, true),
+                ])),
+                false,
+            )),
+            false,
+        ),
+        ColumnType::Vector { typ, dimensions } => DataType::FixedSizeList(
+            Arc::new(Field::new_list_field(arrow_type(typ)?, true)),
+            (*dimensions).into(),
+        ),
+        ColumnType::UserDefinedType { definition, .. } => DataType::Struct(
+            definition
+                .field_types
+                .iter()
+                .map(|(name, typ)| Ok(Field::new(name.as_ref(), arrow_type(typ)?, true)))
+                .collect::<Result<Fields>>()?,
+        ),
+        // Tuple elements are named after their positions
+        ColumnType::Tuple(element_types) => DataType::Struct(
+            element_types
+                .iter()
+                .enumerate()
+                .map(|(index, typ)| Ok(Field::new(index.to_string(), arrow_type(typ)?, true)))
+                .collect::<Result<Fields>>()?,
+        ),
+        other => {
+            return Err(js_typed_error(
+                format!("Missing Arrow representation for CQL type {other:?}"),
+                ErrorType::NotSupportedError,
+            ));
+        }
+    })
+}
+
+/// Gets the builder of the expected type
+fn downcast<B: ArrayBuilder>(builder: &mut dyn ArrayBuilder) -> Result<&mut B> {
+    builder.as_any_mut().downcast_mut::<B>().ok_or_else(|| {
+        js_typed_error(
+            "Arrow builder does not match the type of the column",
+            ErrorType::DriverInternalError,
+        )
+    })
+}
+
+/// Deserializes the value of the given type, if it's not null
+fn read<'frame, 'metadata, T: DeserializeValue<'frame, 'metadata>>(
+    typ: &'metadata ColumnType<'metadata>,
+    slice: Option<FrameSlice<'frame>>,
+) -> Result<Option<T>> {
+    slice.map(|slice| deserialize(typ, slice)).transpose()
+}
+
+/// Appends the value of the given type to the builder created for this type,
+/// reading it straight from the frame slice. Missing slice represents null value.
+pub(crate) fn append_value<'frame, 'metadata>(
+    builder: &mut dyn ArrayBuilder,
+    typ: &'metadata ColumnType<'metadata>,
+    slice: Option<FrameSlice<'frame>>,
+) -> Result<()> {
+    if let Some(slice) = &slice {
+        ensure_not_empty(typ, slice)?;
+    }
+
+    match typ {
+        ColumnType::Native(native_type) => match native_type {
+            NativeType::Ascii | NativeType::Text => {
+                downcast::<StringBuilder>(builder)?.append_option(read::<&str>(typ, slice)?)
+            }
+            NativeType::Boolean => {
+                downcast::<BooleanBuilder>(builder)?.append_option(read(typ, slice)?)
+            }
+            NativeType::Blob => {
+                downcast::<BinaryBuilder>(builder)?.append_option(read::<&[u8]>(typ, slice)?)
+            }
+            NativeType::Counter => downcast::<Int64Builder>(builder)?
+                .append_option(read::<Counter>(typ, slice)?.map(|val| val.0)),
+            NativeType::BigInt => {
+                downcast::<Int64Builder>(builder)?.append_option(read(typ, slice)?)
+            }
+            NativeType::Date => downcast::<Date32Builder>(builder)?.append_option(
+                read::<CqlDate>(typ, slice)?
+                    // Any u32 shifted by 2^31 fits into i32
+                    .map(|val| (val.0 as i64 - UNIX_EPOCH_DATE) as i32),
+            ),
+            NativeType::Decimal => downcast::<StringBuilder>(builder)?.append_option(
+                read::<CqlDecimalBorrowed>(typ, slice)?.map(|val| {
+                    let (value, scale) = val.as_signed_be_bytes_slice_and_exponent();
+                    format_decimal(value, scale)
+                }),
+            ),
+            NativeType::Double => {
+                downcast::<Float64Builder>(builder)?.append_option(read(typ, slice)?)
+            }
+            NativeType::Duration => downcast::<IntervalMonthDayNanoBuilder>(builder)?
+                .append_option(
+                    read::<CqlDuration>(typ, slice)?.map(|val| {
+                        IntervalMonthDayNano::new(val.months, val.days, val.nanoseconds)
+                    }),
+                ),
+            NativeType::Float => {
+                downcast::<Float32Builder>(builder)?.append_option(read(typ, slice)?)
+            }
+            NativeType::Inet => downcast::<StringBuilder>(builder)?
+                .append_option(read::<IpAddr>(typ, slice)?.map(|val| val.to_string())),
+            NativeType::Int => downcast::<Int32Builder>(builder)?.append_option(read(typ, slice)?),
+            NativeType::SmallInt => {
+                downcast::<Int16Builder>(builder)?.append_option(read(typ, slice)?)
+            }
+            NativeType::TinyInt => {
+                downcast::<Int8Builder>(builder)?.append_option(read(typ, slice)?)
+            }
+            NativeType::Time => downcast::<Time64NanosecondBuilder>(builder)?
+                .append_option(read::<CqlTime>(typ, slice)?.map(|val| val.0)),
+            NativeType::Timestamp => downcast::<TimestampMillisecondBuilder>(builder)?
+                .append_option(read::<CqlTimestamp>(typ, slice)?.map(|val| val.0)),
+            NativeType::Timeuuid => {
+                let uuid = read::<CqlTimeuuid>(typ, slice)?;
+                append_uuid(builder, uuid.as_ref().map(|val| val.as_bytes()))?
+            }
+            NativeType::Uuid => {
+                let uuid = read::<uuid::Uuid>(typ, slice)?;
+                append_uuid(builder, uuid.as_ref().map(|val| val.as_bytes()))?
+            }
+            NativeType::Varint => downcast::<StringBuilder>(builder)?.append_option(
+                read::<CqlVarintBorrowed>(typ, slice)?.map(|val| {
+                    num_bigint::BigInt::from_signed_bytes_be(val.as_signed_bytes_be_slice())
+                        .to_string()
+                }),
+            ),
+            other => {
+                return Err(js_typed_error(
+                    format!("Missing Arrow representation for CQL native type {other:?}"),
+                    ErrorType::NotSupportedError,
+                ));
+            }
+        },
+        ColumnType::Collection {
+            typ: CollectionType::List(_) | CollectionType::Set(_),
+            ..
+        } => {
+            let list = downcast::<ListBuilder<Box<dyn ArrayBuilder>>>(builder)?;
+            let Some(elements) = read::<ListlikeIterator<FrameSliceWithMetadata>>(typ, slice)?
+            else {
+                list.append_null();
+                return Ok(());
+            };
+            for element in elements {
+                let element = element.map_err(err_to_napi)?;
+                append_value(
+                    list.values().as_mut(),
+                    element.column_type,
+                    element.frame_slice,
+                )?;
+            }
+            list.append(true);
+        }
+        ColumnType::Collection {
+            typ: CollectionType::Map(_, _),
+            ..
+        } => {
+            let map =
+                downcast::<MapBuilder<Box<dyn ArrayBuilder>, Box<dyn ArrayBuilder>>>(builder)?;
+            let Some(entries) =
+                read::<MapIterator<FrameSliceWithMetadata, FrameSliceWithMetadata>>(typ, slice)?
+            else {
+                return map.append(false).map_err(err_to_napi);
+            };
+            for entry in entries {
+                let (key, value) = entry.map_err(err_to_napi)?;
+                append_value(map.keys().as_mut(), key.column_type, key.frame_slice)?;
+                append_value(map.values().as_mut(), value.column_type, value.frame_slice)?;
+            }
+            map.append(true).map_err(err_to_napi)?;
+        }
+        ColumnType::Vector {
+            typ: element_type,
+            dimensions,
+        } => {
+            let vector = downcast::<FixedSizeListBuilder<Box<dyn ArrayBuilder>>>(builder)?;
+            match read::<VectorIterator<FrameSliceWithMetadata>>(typ, slice)? {
+                Some(elements) => {
+                    for element in elements {
+                        let element = element.map_err(err_to_napi)?;
+                        append_value(
+                            vector.values().as_mut(),
+                            element.column_type,
+                            element.frame_slice,
+                        )?;
+                    }
+                    vector.append(true);
+                }
+                None => {
+                    // Null vector still takes the space of all its elements
+                    for _ in 0..*dimensions {
+                        append_value(vector.values().as_mut(), element_type, None)?;
+                    }
+                    vector.append(false);
+                }
+            }
+        }
+        ColumnType::UserDefinedType { definition, .. } => {
+            let fields = downcast::<StructBuilder>(builder)?;
+            match read::<UdtIterator>(typ, slice)? {
+                Some(values) => {
+                    for (((_, field_type), value), field_builder) in
+                        values.zip(fields.field_builders_mut())
+                    {
+                        let value = value.map_err(err_to_napi)?.flatten();
+                        append_value(field_builder.as_mut(), field_type, value)?;
+                    }
+                    fields.append(true);
+                }
+                None => {
+                    // Null struct still needs a value for each of its fields
+                    for ((_, field_type), field_builder) in definition
+                        .field_types
+                        .iter()
+                        .zip(fields.field_builders_mut())
+                    {
+                        append_value(field_builder.as_mut(), field_type, None)?;
+                    }
+                    fields.append(false);
+                }
+            }
+        }
+        ColumnType::Tuple(element_types) => {
+            let elements = downcast::<StructBuilder>(builder)?;
+            let is_valid = slice.is_some();
+            let mut slice = slice;
+            for (element_type, element_builder) in
+                element_types.iter().zip(elements.field_builders_mut())
+            {
+                // If there are no bytes left to read, the element is considered as null.
+                // This handles tuples with fewer elements than expected.
+                let value = match &mut slice {
+                    Some(slice) if !slice.is_empty() => {
+                        slice.read_cql_bytes().map_err(err_to_napi)?
+                    }
+                    _ => None,
+                };
+                append_value(element_builder.as_mut(), element_type, value)?;
+            }
+            elements.append(is_valid);
+        }
+        other => {
+            return Err(js_typed_error(
+                format!("Missing Arrow representation for CQL type {other:?}"),
+                ErrorType::NotSupportedError,
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Appends the bytes of the UUID to the fixed size binary builder
+fn append_uuid(builder: &mut dyn ArrayBuilder, uuid: Option<&[u8; 16]>) -> Result<()> {
+    let builder = downcast::<FixedSizeBinaryBuilder>(builder)?;
+    match uuid {
+        Some(uuid) => builder.append_value(uuid).map_err(err_to_napi),
+        None => {
+            builder.append_null();
+            Ok(())
+        }
+    }
+}
+
+/// Formats the decimal, given as unscaled value in the big endian two's complement
+/// representation and its scale, in the plain (not scientific) notation.
+fn format_decimal(unscaled: &[u8], scale: i32) -> String {
+    let unscaled = num_bigint::BigInt::from_signed_bytes_be(unscaled);
+    let digits = unscaled.magnitude().to_string();
+    let sign = if unscaled.sign() == num_bigint::Sign::Minus {
+        "-"
+    } else {
+        ""
+    };
+    if scale <= 0 {
+        let zeros = if unscaled.sign() == num_bigint::Sign::NoSign {
+            0
+        } else {
+            scale.unsigned_abs() as usize
+        };
+        return format!("{sign}{digits}{}", "0".repeat(zeros));
+    }
+    let scale = scale as usize;
+    let digits = format!("{digits:0>width$}", width = scale + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - scale);
+    format!("{sign}{integer}.{fraction}")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::{Array, Int32Array, RecordBatch, StringArray};
+    use arrow_ipc::reader::StreamReader;
+    use arrow_schema::{DataType, Field, Schema};
+
+    use super::{format_decimal, write_ipc};
+
+    #[test]
+    fn writes_ipc_pages_into_single_stream() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, true),
+            Field::new("name", DataType::Utf8, true),
+        ]));
+        let page = |ids: Vec<Option<i32>>, names: Vec<Option<&str>>| {
+            RecordBatch::try_new(
+                schema.clone(),
+                vec![
+                    Arc::new(Int32Array::from(ids)),
+                    Arc::new(StringArray::from(names)),
+                ],
+            )
+            .unwrap()
+        };
+        let first = page(vec![Some(1), None], vec![Some("a"), Some("b")]);
+        let second = page(vec![Some(3)], vec![None]);
+
+        let mut stream = write_ipc(&first, true, false).unwrap();
+        stream.extend(write_ipc(&second, false, true).unwrap());
+
+        let batches = StreamReader::try_new(stream.as_slice(), None)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(batches, vec![first, second]);
+        assert_eq!(batches[1].column(1).null_count(), 1);
+    }
+
+    #[test]
+    fn formats_decimals() {
+        assert_eq!(format_decimal(&[0x30, 0x39], 2), "123.45");
+        assert_eq!(format_decimal(&[0x30, 0x39], 0), "12345");
+        assert_eq!(format_decimal(&[0x30, 0x39], -2), "1234500");
+        assert_eq!(format_decimal(&[0x05], 3), "0.005");
+        assert_eq!(format_decimal(&[0xF6], 1), "-1.0");
+        assert_eq!(format_decimal(&[0x00], -3), "0");
+    }
+}
@@ -22,7 +22,7 @@ pub struct PlainTextAuthProvider {
 // But this is small enough error, to just don't expose constructors as constructors
 #[napi]
 impl PlainTextAuthProvider {
-    #[napi]
+    #[napi(catch_unwind)]
     // #[napi(constructor)]
     pub fn new() -> Self {
         println!("Plain text constructor!");
@@ -32,7 +32,7 @@ impl PlainTextAuthProvider {
         }
     }
 
-    #[napi]
+    #[napi(catch_unwind)]
     pub fn test(&self) -> u32 {
         println!(
             "Plain text function!: accessing internal: {}",
@@ -0,0 +1,394 @@
+use std::future::Future;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use futures::stream::{self, FuturesUnordered, Stream, StreamExt};
+use napi::{JsValue, bindgen_prelude::JsObjectValue};
+use scylla::client::caching_session::CachingSession;
+use scylla::statement::prepared::PreparedStatement;
+use tokio::sync::{mpsc, watch};
+use tokio::task::JoinHandle;
+
+use crate::errors::{ErrorType, err_to_napi, js_error, js_typed_error};
+use crate::requests::execution_history::ExecutionHistory;
+use crate::result::QueryResultWrapper;
+use crate::session::{OwnedSessionGuard, session_closed};
+use crate::types::encoded_data::EncodedValuesWrapper;
+use crate::utils::to_napi_obj::define_rust_to_js_convertible_object;
+
+// Error is passed in the same format as the errors thrown from napi functions,
+// so that it can be converted into the JS error of the matching type.
+define_rust_to_js_convertible_object!(ConcurrentExecutionError {
+    index,
+    index: u32,
+    error,
+    error: String
+});
+
+// Results are collected only when requested.
+// Results of the failed executions are set to null.
+define_rust_to_js_convertible_object!(ConcurrentExecutionResult {
+    total_executed,
+    totalExecuted: u32,
+    errors,
+    errors: Vec<ConcurrentExecutionError>,
+    results,
+    results: Option<Vec<Option<QueryResultWrapper>>>
+});
+
+/// Settings of the concurrent execution.
+#[derive(Clone, Copy)]
+pub(crate) struct ConcurrencyOptions {
+    concurrency_level: usize,
+    raise_on_first_error: bool,
+    collect_results: bool,
+}
+
+impl ConcurrencyOptions {
+    pub(crate) fn new(
+        concurrency_level: u32,
+        raise_on_first_error: bool,
+        collect_results: bool,
+    ) -> napi::Result<Self> {
+        if concurrency_level == 0 {
+            return Err(js_typed_error(
+                "Concurrency level must be positive",
+                ErrorType::ArgumentError,
+            ));
+        }
+        Ok(ConcurrencyOptions {
+            concurrency_level: concurrency_level as usize,
+            raise_on_first_error,
+            collect_results,
+        })
+    }
+}
+
+/// Outcome of the concurrent execution, with the errors in the format of `ConcurrentExecutionError`.
+pub(crate) struct ConcurrentOutcome<R> {
+    total_executed: u32,
+    errors: Vec<ConcurrentExecutionError>,
+    results: Option<Vec<Option<R>>>,
+}
+
+impl From<ConcurrentOutcome<QueryResultWrapper>> for ConcurrentExecutionResult {
+    fn from(outcome: ConcurrentOutcome<QueryResultWrapper>) -> Self {
+        ConcurrentExecutionResult {
+            total_executed: outcome.total_executed,
+            errors: outcome.errors,
+            results: outcome.results,
+        }
+    }
+}
+
+/// Executes each of the rows with `execute`, with at most `concurrency_level`
+/// executions in progress at any time.
+///
+/// The rows are read from the input only when there is a free slot for the execution,
+/// so a slow consumer applies backpressure to the input.
+/// When `raise_on_first_error` is set, no new executions are started after the first error,
+/// and the executions in progress are dropped.
+pub(crate) async fn execute_concurrent<T, R, F, Fut>(
+    rows: impl Stream<Item = T>,
+    options: ConcurrencyOptions,
+    execute: F,
+) -> ConcurrentOutcome<R>
+where
+    F: Fn(T) -> Fut,
+    Fut: Future<Output = Result<R, String>>,
+{
+    let mut rows = std::pin::pin!(rows);
+    let mut in_flight = FuturesUnordered::new();
+    let mut input_ended = false;
+    let mut next_index: u32 = 0;
+    let mut outcome = ConcurrentOutcome {
+        total_executed: 0,
+        errors: vec![],
+        results: options.collect_results.then(Vec::new),
+    };
+
+    loop {
+        let can_read = !input_ended && in_flight.len() < options.concurrency_level;
+        if !can_read && in_flight.is_empty() {
+            break;
+        }
+        tokio::select! {
+            row = rows.next(), if can_read => match row {
+                Some(row) => {
+                    let index = next_index;
+                    next_index += 1;
+                    if let Some(results) = &mut outcome.results {
+                        results.push(None);
+                    }
+                    let execution = execute(row);
+                    in_flight.push(async move { (index, execution.await) });
+                }
+                None => input_ended = true,
+            },
+            Some((index, execution)) = in_flight.next(), if !in_flight.is_empty() => {
+                outcome.total_executed += 1;
+                match execution {
+                    Ok(result) => {
+                        if let Some(results) = &mut outcome.results {
+                            results[index as usize] = Some(result);
+                        }
+                    }
+                    Err(error) => {
+                        outcome.errors.push(ConcurrentExecutionError { index, error });
+                        if options.raise_on_first_error {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    outcome
+}
+
+/// Executes the prepared statement with the values of a single row.
+async fn execute_prepared(
+    session: &CachingSession,
+    prepared: &PreparedStatement,
+    row: Vec<EncodedValuesWrapper>,
+) -> Result<QueryResultWrapper, String> {
+    // Prepared statements share the state, so a copy with its own history listener
+    // is cheap to create for each of the executions.
+    let mut prepared = prepared.clone();
+    let history = Arc::new(ExecutionHistory::for_statement(
+        session.get_session(),
+        prepared.get_consistency(),
+        prepared.get_execution_profile_handle(),
+    ));
+    prepared.set_history_listener(history.clone());
+    session
+        .get_session()
+        .execute_unpaged(&prepared, row)
+        .await
+        .map_err(err_to_napi)
+        .and_then(|result| QueryResultWrapper::from_query(result, &history))
+        .map_err(|error| error.reason)
+}
+
+/// Resolves once the session starts closing.
+async fn session_closing(mut closing: watch::Receiver<bool>) {
+    // The sender is dropped only together with the session, which is then closed as well.
+    let _ = closing.wait_for(|closing| *closing).await;
+}
+
+/// Executor of the prepared statement for the rows provided in chunks, for example read from a JS stream.
+///
+/// The rows are executed in the background, with the same rules as in `execute_concurrent`.
+/// The execution holds the session guard, so closing the session waits for it to end.
+/// To not block closing, the executor stops accepting rows once the session starts closing,
+/// and the execution is aborted when the executor is dropped without being finished.
+#[napi]
+pub struct ConcurrentExecutor {
+    rows: Mutex<Option<mpsc::Sender<Vec<EncodedValuesWrapper>>>>,
+    execution: Mutex<Option<JoinHandle<ConcurrentExecutionResult>>>,
+    closing: watch::Receiver<bool>,
+}
+
+impl ConcurrentExecutor {
+    /// Starts the execution with the provided session, or with the keyspace session, if there is one.
+    pub(crate) fn start(
+        session: OwnedSessionGuard,
+        keyspace_session: Option<Arc<CachingSession>>,
+        prepared: PreparedStatement,
+        options: ConcurrencyOptions,
+        closing: watch::Receiver<bool>,
+    ) -> Self {
+        // The queue, the executions in progress and a single pushed chunk bound the number of rows
+        // kept in memory at the same time.
+        let (sender, mut receiver) = mpsc::channel(options.concurrency_level);
+        let rows = stream::poll_fn(move |cx| receiver.poll_recv(cx))
+            .take_until(session_closing(closing.clone()));
+        let execution = async move {
+            let session = keyspace_session.as_deref().unwrap_or(&session);
+            let prepared = &prepared;
+            execute_concurrent(rows, options, |row| {
+                execute_prepared(session, prepared, row)
+            })
+            .await
+            .into()
+        };
+        let execution = tokio::spawn(execution);
+        ConcurrentExecutor {
+            rows: Mutex::new(Some(sender)),
+            execution: Mutex::new(Some(execution)),
+            closing,
+        }
+    }
+}
+
+impl Drop for ConcurrentExecutor {
+    fn drop(&mut self) {
+        let execution = self
+            .execution
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(execution) = execution.take() {
+            execution.abort();
+        }
+    }
+}
+
+#[napi]
+impl ConcurrentExecutor {
+    /// Adds the rows to be executed, waiting until there is a space for them in the execution queue.
+    ///
+    /// Returns false when the execution was stopped because of an error,
+    /// in which case no more rows should be provided.
+    /// Fails with `ShutdownError` when the session starts closing.
+    #[napi(catch_unwind)]
+    pub async fn push(&self, rows: Vec<Vec<EncodedValuesWrapper>>) -> napi::Result<bool> {
+        let sender = self
+            .rows
+            .lock()
+            .map_err(js_error)?
+            .clone()
+            .ok_or_else(|| js_error("Rows cannot be added after the execution is finished"))?;
+        for row in rows {
+            tokio::select! {
+                biased;
+                _ = session_closing(self.closing.clone()) => return Err(session_closed()),
+                sent = sender.send(row) => if sent.is_err() {
+                    return Ok(false);
+                },
+            }
+        }
+        Ok(true)
+    }
+
+    /// Marks the end of the rows and waits for all the executions to complete.
+    #[napi(catch_unwind)]
+    pub async fn finish(&self) -> napi::Result<ConcurrentExecutionResult> {
+        self.rows.lock().map_err(js_error)?.take();
+        let execution = self
+            .execution
+            .lock()
+            .map_err(js_error)?
+            .take()
+            .ok_or_else(|| js_error("Execution has already been finished"))?;
+        execution.await.map_err(err_to_napi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use super::*;
+
+    fn options(concurrency_level: usize, raise_on_first_error: bool) -> ConcurrencyOptions {
+        ConcurrencyOptions {
+            concurrency_level,
+            raise_on_first_error,
+            collect_results: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_concurrent_limits_executions_in_progress() {
+        let in_progress = AtomicUsize::new(0);
+        let max_in_progress = AtomicUsize::new(0);
+        let outcome = execute_concurrent(stream::iter(0..20), options(3, true), |row| {
+            let in_progress = &in_progress;
+            let max_in_progress = &max_in_progress;
+            async move {
+                let current = in_progress.fetch_add(1, Ordering::SeqCst) + 1;
+                max_in_progress.fetch_max(current, Ordering::SeqCst);
+                // Later rows complete first
+                tokio::time::sleep(Duration::from_millis(20 - row)).await;
+                in_progress.fetch_sub(1, Ordering::SeqCst);
+                Ok::<_, String>(row * 2)
+            }
+        })
+        .await;
+
+        assert_eq!(max_in_progress.load(Ordering::SeqCst), 3);
+        assert_eq!(outcome.total_executed, 20);
+        assert!(outcome.errors.is_empty());
+        // Results are placed at the index of the row, regardless of the completion order
+        let expected: Vec<_> = (0..20).map(|row| Some(row * 2)).collect();
+        assert_eq!(outcome.results, Some(expected));
+    }
+
+    #[tokio::test]
+    async fn execute_concurrent_collects_errors() {
+        let outcome =
+            execute_concurrent(stream::iter(0..10), options(4, false), |row| async move {
+                if row % 3 == 0 {
+                    Err(format!("Error#{row}"))
+                } else {
+                    Ok(row)
+                }
+            })
+            .await;
+
+        assert_eq!(outcome.total_executed, 10);
+        let mut failed: Vec<_> = outcome
+            .errors
+            .iter()
+            .map(|e| (e.index, e.error.as_str()))
+            .collect();
+        failed.sort();
+        assert_eq!(
+            failed,
+            [
+                (0, "Error#0"),
+                (3, "Error#3"),
+                (6, "Error#6"),
+                (9, "Error#9")
+            ]
+        );
+        let results = outcome.results.unwrap();
+        assert_eq!(results[1], Some(1));
+        assert_eq!(results[3], None);
+    }
+
+    #[tokio::test]
+    async fn execute_concurrent_stops_on_first_error() {
+        let read = AtomicUsize::new(0);
+        let rows = stream::iter(0..100).inspect(|_| {
+            read.fetch_add(1, Ordering::SeqCst);
+        });
+        let outcome = execute_concurrent(rows, options(2, true), |row| async move {
+            if row == 5 {
+                Err("Error#failed".to_owned())
+            } else {
+                Ok(row)
+            }
+        })
+        .await;
+
+        assert_eq!(outcome.errors.len(), 1);
+        assert_eq!(outcome.errors[0].index, 5);
+        // No rows are read after the error, except for the ones that filled the free slots
+        assert!(read.load(Ordering::SeqCst) <= 7);
+    }
+
+    #[tokio::test]
+    async fn execute_concurrent_stops_reading_when_closing_starts() {
+        let (sender, mut receiver) = mpsc::channel(1);
+        let (closing_sender, closing) = watch::channel(false);
+        let rows =
+            stream::poll_fn(move |cx| receiver.poll_recv(cx)).take_until(session_closing(closing));
+        let execution = tokio::spawn(execute_concurrent(
+            rows,
+            options(2, true),
+            |row: u32| async move { Ok::<_, String>(row) },
+        ));
+
+        sender.send(1).await.unwrap();
+        sender.send(2).await.unwrap();
+        // Closing ends the input, even though the sender is still alive
+        closing_sender.send_replace(true);
+        let outcome = tokio::time::timeout(Duration::from_secs(5), execution)
+            .await
+            .expect("Execution should end once closing starts")
+            .unwrap();
+        assert!(outcome.total_executed <= 2);
+        drop(sender);
+    }
+}
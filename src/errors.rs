@@ -10,6 +10,7 @@ use napi::Status;
 /// are native JavaScript error types and the rest are custom
 /// Datastax driver error types.
 pub enum ErrorType {
+    AbortError,
     ArgumentError,
     AuthenticationError,
     BusyConnectionError, // TODO: Add suport for fields of this error
@@ -19,6 +20,7 @@ pub enum ErrorType {
     NotSupportedError,
     OperationTimedOutError, // TODO: Add suport for fields of this error
     ResponseError,          // TODO: Add suport for fields of this error
+    ShutdownError,
     Error,
     RangeError,
     ReferenceError,
@@ -29,6 +31,7 @@ pub enum ErrorType {
 impl Display for ErrorType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str(match self {
+            ErrorType::AbortError => "AbortError",
             ErrorType::ArgumentError => "ArgumentError",
             ErrorType::AuthenticationError => "AuthenticationError",
             ErrorType::BusyConnectionError => "BusyConnectionError",
@@ -38,6 +41,7 @@ impl Display for ErrorType {
             ErrorType::NotSupportedError => "NotSupportedError",
             ErrorType::OperationTimedOutError => "OperationTimedOutError",
             ErrorType::ResponseError => "ResponseError",
+            ErrorType::ShutdownError => "ShutdownError",
             ErrorType::Error => "Error",
             ErrorType::RangeError => "RangeError",
             ErrorType::ReferenceError => "ReferenceError",
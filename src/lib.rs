@@ -1,15 +1,29 @@
 #[macro_use]
 extern crate napi_derive;
 
+// Without the cfg, the Rust driver hides the unstable APIs used by the driver,
+// and the build would fail with much less descriptive errors.
+#[cfg(not(scylla_unstable))]
+compile_error!(
+    "The driver requires `--cfg scylla_unstable` rustflag. \
+    It's set in .cargo/config.toml, but RUSTFLAGS environment variable replaces it, \
+    so when setting RUSTFLAGS, add the cfg to it"
+);
+
 // Link other files
+pub mod arrow;
 pub mod auth;
+pub mod concurrent;
 pub mod errors;
 pub mod metadata;
 pub mod options;
 pub mod paging;
+pub mod policies;
+pub mod pooling;
 pub mod requests;
 pub mod result;
 pub mod session;
+pub mod socket;
 pub mod tests;
 pub mod types;
 pub mod utils;
@@ -16,9 +16,10 @@ define_rust_to_js_convertible_object!(
 impl SessionWrapper {
     /// Due to using Napi structs, this endpoint is not very efficient.
     /// It should be retrieved lazily, whenever user requests information about hosts.
-    #[napi]
-    pub fn get_all_hosts(&self) -> Vec<HostWrapper> {
-        self.inner
+    #[napi(catch_unwind)]
+    pub fn get_all_hosts(&self) -> napi::Result<Vec<HostWrapper>> {
+        Ok(self
+            .session()?
             .get_session()
             .get_cluster_state()
             .get_nodes_info()
@@ -29,6 +30,6 @@ impl SessionWrapper {
                 datacenter: node.datacenter.clone(),
                 rack: node.rack.clone(),
             })
-            .collect()
+            .collect())
     }
 }
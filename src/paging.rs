@@ -15,14 +15,14 @@ pub struct PagingStateResponseWrapper {
 
 #[napi]
 impl PagingStateWrapper {
-    #[napi]
+    #[napi(catch_unwind)]
     pub fn from_buffer(value: Buffer) -> PagingStateWrapper {
         PagingStateWrapper {
             inner: PagingState::new_from_raw_bytes(&*value),
         }
     }
 
-    #[napi]
+    #[napi(catch_unwind)]
     pub fn get_raw_page_state(&self) -> Buffer {
         self.inner
             .as_bytes_slice()
@@ -73,13 +73,13 @@ impl PagingStateResponseWrapper {
     /// Determines if the query has finished
     /// or it should be resumed with
     /// given PagingState in order to fetch next pages.
-    #[napi]
+    #[napi(catch_unwind)]
     pub fn has_next_page(&self) -> bool {
         !self.inner.finished()
     }
 
     /// Get the next page of the given query, assuming there are pages left
-    #[napi]
+    #[napi(catch_unwind)]
     pub fn next_page(&self) -> napi::Result<PagingStateWrapper> {
         Ok(PagingStateWrapper {
             inner: match &self.inner {
@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use napi::bindgen_prelude::{FnArgs, FromNapiValue, Promise};
+use napi::threadsafe_function::ThreadsafeFunction;
+use napi::{Status, sys};
+use scylla::errors::TranslationError;
+use scylla::policies::address_translator::{AddressTranslator, UntranslatedPeer};
+
+use crate::errors::{ErrorType, js_typed_error};
+
+/// Time after which the translation by a JS function fails,
+/// so that a translator that never calls back doesn't stall the session.
+const JS_TRANSLATION_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// JS function translating the address and port of a peer into the "ip:port" string.
+///
+/// The function is expected to return a promise, so that the (possibly slow) translation
+/// does not block the JS event loop.
+/// The function is weak, so it does not prevent the Node.js process from exiting.
+/// The address and the port are passed as separate arguments.
+type TranslateFunction = ThreadsafeFunction<
+    FnArgs<(String, u16)>,
+    Promise<String>,
+    FnArgs<(String, u16)>,
+    Status,
+    false,
+    true,
+>;
+
+/// Address translator that delegates the translation to a JS function.
+///
+/// Used to support the JS AddressTranslator policies (like EC2MultiRegionTranslator),
+/// which translate the addresses with a callback.
+pub struct JsAddressTranslator {
+    translate: TranslateFunction,
+    timeout: Duration,
+}
+
+impl fmt::Debug for JsAddressTranslator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JsAddressTranslator")
+            .finish_non_exhaustive()
+    }
+}
+
+impl FromNapiValue for JsAddressTranslator {
+    /// # Safety
+    ///
+    /// Valid pointer to napi env must be provided
+    unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> napi::Result<Self> {
+        Ok(JsAddressTranslator {
+            // Caller of this function ensures a valid pointer to napi env is provided
+            translate: unsafe { TranslateFunction::from_napi_value(env, napi_val) }?,
+            timeout: JS_TRANSLATION_TIMEOUT,
+        })
+    }
+}
+
+impl JsAddressTranslator {
+    pub(crate) fn with_timeout(self, timeout: Duration) -> Self {
+        JsAddressTranslator { timeout, ..self }
+    }
+
+    /// Translates the address with the JS function, failing if it doesn't respond within the timeout.
+    pub(crate) async fn translate(
+        &self,
+        address: SocketAddr,
+    ) -> Result<SocketAddr, TranslationError> {
+        let translation = async {
+            self.translate
+                .call_async_catch((address.ip().to_string(), address.port()).into())
+                .await
+                .map_err(|e| translation_io_error(address, e))?
+                .await
+                .map_err(|e| translation_io_error(address, e))
+        };
+        let translated = tokio::time::timeout(self.timeout, translation)
+            .await
+            .map_err(|_| {
+                translation_io_error(
+                    address,
+                    format!(
+                        "translator did not respond within {} ms",
+                        self.timeout.as_millis()
+                    ),
+                )
+            })??;
+
+        parse_endpoint(&translated).map_err(|e| {
+            translation_io_error(
+                address,
+                format!("Translated address {translated} is invalid: {e}"),
+            )
+        })
+    }
+}
+
+#[async_trait]
+impl AddressTranslator for JsAddressTranslator {
+    async fn translate_address(
+        &self,
+        untranslated_peer: &UntranslatedPeer,
+    ) -> Result<SocketAddr, TranslationError> {
+        self.translate(untranslated_peer.untranslated_address())
+            .await
+    }
+}
+
+fn translation_io_error(address: SocketAddr, error: impl fmt::Display) -> TranslationError {
+    TranslationError::IoError(Arc::new(std::io::Error::other(format!(
+        "Failed to translate address {address}: {error}"
+    ))))
+}
+
+/// Address translator using a static map of addresses.
+///
+/// Peers with addresses not present in the map keep their untranslated address,
+/// the same as with the default JS AddressTranslator.
+#[derive(Debug)]
+pub struct StaticAddressTranslator {
+    address_map: HashMap<SocketAddr, SocketAddr>,
+}
+
+impl StaticAddressTranslator {
+    /// Creates the translator from the map of "ip:port" addresses.
+    pub(crate) fn new(address_map: &HashMap<String, String>) -> napi::Result<Self> {
+        Ok(StaticAddressTranslator {
+            address_map: address_map
+                .iter()
+                .map(|(from, to)| Ok((parse_socket_address(from)?, parse_socket_address(to)?)))
+                .collect::<napi::Result<_>>()?,
+        })
+    }
+
+    fn translate(&self, address: SocketAddr) -> SocketAddr {
+        self.address_map.get(&address).copied().unwrap_or(address)
+    }
+}
+
+#[async_trait]
+impl AddressTranslator for StaticAddressTranslator {
+    async fn translate_address(
+        &self,
+        untranslated_peer: &UntranslatedPeer,
+    ) -> Result<SocketAddr, TranslationError> {
+        Ok(self.translate(untranslated_peer.untranslated_address()))
+    }
+}
+
+/// Parses the endpoint in the format of JS address translators, that is "ip:port".
+///
+/// IPv6 addresses are accepted both with brackets ("[::1]:9042"), and without them ("::1:9042"),
+/// as JS translators concatenate the address and the port.
+fn parse_endpoint(endpoint: &str) -> Result<SocketAddr, String> {
+    if let Ok(address) = endpoint.parse() {
+        return Ok(address);
+    }
+    let (ip, port) = endpoint
+        .rsplit_once(':')
+        .ok_or_else(|| "expected ip:port".to_owned())?;
+    let ip: IpAddr = ip.parse().map_err(|e| format!("{e}"))?;
+    let port: u16 = port.parse().map_err(|e| format!("invalid port: {e}"))?;
+    Ok(SocketAddr::new(ip, port))
+}
+
+fn parse_socket_address(address: &str) -> napi::Result<SocketAddr> {
+    parse_endpoint(address).map_err(|e| {
+        js_typed_error(
+            format!("Invalid address in the address map: {address} ({e})"),
+            ErrorType::ArgumentError,
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{StaticAddressTranslator, parse_endpoint};
+
+    #[test]
+    fn static_address_translator_translates_mapped_addresses() {
+        let translator = StaticAddressTranslator {
+            address_map: HashMap::from([
+                (
+                    "10.0.0.1:9042".parse().unwrap(),
+                    "203.0.113.1:9042".parse().unwrap(),
+                ),
+                (
+                    "[::1]:9042".parse().unwrap(),
+                    "[2001:db8::1]:19042".parse().unwrap(),
+                ),
+            ]),
+        };
+        assert_eq!(
+            translator.translate("10.0.0.1:9042".parse().unwrap()),
+            "203.0.113.1:9042".parse().unwrap()
+        );
+        assert_eq!(
+            translator.translate("[::1]:9042".parse().unwrap()),
+            "[2001:db8::1]:19042".parse().unwrap()
+        );
+        assert_eq!(
+            translator.translate("10.0.0.2:9042".parse().unwrap()),
+            "10.0.0.2:9042".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_endpoint_accepts_ipv6_with_and_without_brackets() {
+        assert_eq!(
+            parse_endpoint("10.0.0.1:9042"),
+            Ok("10.0.0.1:9042".parse().unwrap())
+        );
+        assert_eq!(
+            parse_endpoint("[2001:db8::1]:9042"),
+            Ok("[2001:db8::1]:9042".parse().unwrap())
+        );
+        // Format returned by the default JS AddressTranslator
+        assert_eq!(
+            parse_endpoint("2001:db8::1:9042"),
+            Ok("[2001:db8::1]:9042".parse().unwrap())
+        );
+        assert_eq!(
+            parse_endpoint("::1:19042"),
+            Ok("[::1]:19042".parse().unwrap())
+        );
+        assert!(parse_endpoint("10.0.0.1").is_err());
+        assert!(parse_endpoint("host:9042").is_err());
+        assert!(parse_endpoint("10.0.0.1:port").is_err());
+    }
+}
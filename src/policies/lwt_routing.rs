@@ -0,0 +1,81 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use scylla::cluster::{ClusterState, NodeRef};
+use scylla::errors::RequestAttemptError;
+use scylla::policies::load_balancing::{FallbackPlan, LoadBalancingPolicy, RoutingInfo};
+use scylla::routing::Shard;
+
+/// Load balancing policy, that routes all the requests as lightweight transactions (LWT).
+///
+/// The Rust driver routes prepared statements as LWT based on the metadata returned when preparing them,
+/// but it never does so for batches. This policy wraps the policy of a batch that contains
+/// conditional updates, so that it's routed to the replicas in the same, deterministic order
+/// (primary replica first), like the single conditional updates.
+#[derive(Debug)]
+pub(crate) struct LwtRoutingPolicy {
+    inner: Arc<dyn LoadBalancingPolicy>,
+}
+
+impl LwtRoutingPolicy {
+    pub(crate) fn new(inner: Arc<dyn LoadBalancingPolicy>) -> Self {
+        LwtRoutingPolicy { inner }
+    }
+}
+
+/// Returns the same routing information, marked as LWT.
+fn lwt_request<'a>(request: &RoutingInfo<'a>) -> RoutingInfo<'a> {
+    let mut request = request.clone();
+    request.is_confirmed_lwt = true;
+    request
+}
+
+impl LoadBalancingPolicy for LwtRoutingPolicy {
+    fn pick<'a>(
+        &'a self,
+        request: &'a RoutingInfo,
+        cluster: &'a ClusterState,
+    ) -> Option<(NodeRef<'a>, Option<Shard>)> {
+        let request = lwt_request(request);
+        // The wrapped policy returns the node borrowed for the lifetime of the local request,
+        // so the same node is taken from the cluster state instead.
+        let (node, shard) = self.inner.pick(&request, cluster)?;
+        Some((cluster.get_node_by_host_id(node.host_id)?, shard))
+    }
+
+    fn fallback<'a>(
+        &'a self,
+        request: &'a RoutingInfo,
+        cluster: &'a ClusterState,
+    ) -> FallbackPlan<'a> {
+        let request = lwt_request(request);
+        let plan: Vec<_> = self
+            .inner
+            .fallback(&request, cluster)
+            .map(|(node, shard)| (node.host_id, shard))
+            .collect();
+        Box::new(plan.into_iter().filter_map(|(host_id, shard)| {
+            cluster
+                .get_node_by_host_id(host_id)
+                .map(|node| (node, shard))
+        }))
+    }
+
+    fn on_request_success(&self, request: &RoutingInfo, latency: Duration, node: NodeRef<'_>) {
+        self.inner.on_request_success(request, latency, node);
+    }
+
+    fn on_request_failure(
+        &self,
+        request: &RoutingInfo,
+        latency: Duration,
+        node: NodeRef<'_>,
+        error: &RequestAttemptError,
+    ) {
+        self.inner.on_request_failure(request, latency, node, error);
+    }
+
+    fn name(&self) -> String {
+        format!("LwtRoutingPolicy({})", self.inner.name())
+    }
+}
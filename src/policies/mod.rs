@@ -0,0 +1,3 @@
+pub mod address_translation;
+pub mod lwt_routing;
+pub mod reconnection;
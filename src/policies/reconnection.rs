@@ -0,0 +1,305 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicU64, Ordering},
+};
+use std::time::Duration;
+
+use napi::{JsValue, bindgen_prelude::JsObjectValue};
+use scylla::policies::reconnect::{
+    ConstantReconnectPolicy, ExponentialReconnectPolicy, ReconnectPolicy, ReconnectPolicySession,
+};
+
+use crate::errors::{ErrorType, js_typed_error};
+use crate::session::SessionWrapper;
+use crate::utils::{
+    from_napi_obj::define_js_to_rust_convertible_object,
+    to_napi_obj::define_rust_to_js_convertible_object,
+};
+
+/// Reconnection policies, matching the policies available in JS driver.
+#[napi]
+#[derive(Debug)]
+pub enum ReconnectionPolicyKind {
+    Constant,
+    Exponential,
+}
+
+// All delays are expressed in milliseconds.
+// Constant policy uses only the delay, and exponential policy uses base and max delays,
+// optionally starting each reconnection without any delay.
+define_js_to_rust_convertible_object!(ReconnectionPolicyOptions {
+    kind,
+    kind: ReconnectionPolicyKind,
+    delay,
+    delay: u32,
+    base_delay,
+    baseDelay: u32,
+    max_delay,
+    maxDelay: u32,
+    start_with_no_delay,
+    startWithNoDelay: bool
+});
+
+define_rust_to_js_convertible_object!(ReconnectionMetrics {
+    failed_attempts,
+    failedAttempts: i64,
+    reconnections,
+    reconnections: i64
+});
+
+/// Counters of the connection pool fill attempts, shared by all the pools of the session.
+#[derive(Debug, Default)]
+pub(crate) struct ReconnectionCounters {
+    failed_attempts: AtomicU64,
+    reconnections: AtomicU64,
+}
+
+/// Reconnect policy that counts the reconnection attempts of the connection pools,
+/// delegating the delays to the wrapped policy.
+#[derive(Debug)]
+pub(crate) struct ObservedReconnectPolicy {
+    inner: Arc<dyn ReconnectPolicy>,
+    counters: Arc<ReconnectionCounters>,
+}
+
+#[derive(Debug)]
+struct ObservedReconnectPolicySession {
+    inner: Box<dyn ReconnectPolicySession>,
+    counters: Arc<ReconnectionCounters>,
+    reconnecting: bool,
+}
+
+/// Reconnect policy that makes the first attempt of each reconnection without any delay,
+/// delegating the delays of the following attempts to the wrapped policy.
+#[derive(Debug)]
+struct NoInitialDelayReconnectPolicy {
+    inner: Arc<dyn ReconnectPolicy>,
+}
+
+#[derive(Debug)]
+struct NoInitialDelayReconnectPolicySession {
+    inner: Box<dyn ReconnectPolicySession>,
+    reconnecting: bool,
+    no_delay: bool,
+}
+
+impl ReconnectionPolicyOptions {
+    /// Creates the reconnect policy of the Rust driver, validating the provided delays.
+    pub(crate) fn to_reconnect_policy(&self) -> napi::Result<Arc<dyn ReconnectPolicy>> {
+        Ok(match self.kind {
+            Some(ReconnectionPolicyKind::Constant) => {
+                let delay = self.delay.ok_or_else(|| {
+                    js_typed_error(
+                        "Constant reconnection policy requires a delay",
+                        ErrorType::ArgumentError,
+                    )
+                })?;
+                Arc::new(ConstantReconnectPolicy::new(Duration::from_millis(
+                    delay.into(),
+                )))
+            }
+            Some(ReconnectionPolicyKind::Exponential) => {
+                let (Some(base_delay), Some(max_delay)) = (self.base_delay, self.max_delay) else {
+                    return Err(js_typed_error(
+                        "Exponential reconnection policy requires both base and max delay",
+                        ErrorType::ArgumentError,
+                    ));
+                };
+                if base_delay > max_delay {
+                    return Err(js_typed_error(
+                        format!(
+                            "Base delay ({base_delay}ms) of the exponential reconnection policy \
+                            cannot be greater than max delay ({max_delay}ms)"
+                        ),
+                        ErrorType::ArgumentError,
+                    ));
+                }
+                let policy = Arc::new(ExponentialReconnectPolicy::new().with_backoff_limits(
+                    Duration::from_millis(base_delay.into()),
+                    Duration::from_millis(max_delay.into()),
+                ));
+                if self.start_with_no_delay.unwrap_or(false) {
+                    Arc::new(NoInitialDelayReconnectPolicy { inner: policy })
+                } else {
+                    policy
+                }
+            }
+            None => Arc::new(ExponentialReconnectPolicy::new()),
+        })
+    }
+}
+
+impl ObservedReconnectPolicy {
+    pub(crate) fn new(
+        inner: Arc<dyn ReconnectPolicy>,
+        counters: Arc<ReconnectionCounters>,
+    ) -> Self {
+        ObservedReconnectPolicy { inner, counters }
+    }
+}
+
+impl ReconnectPolicy for ObservedReconnectPolicy {
+    fn new_session(&self) -> Box<dyn ReconnectPolicySession> {
+        Box::new(ObservedReconnectPolicySession {
+            inner: self.inner.new_session(),
+            counters: Arc::clone(&self.counters),
+            reconnecting: false,
+        })
+    }
+}
+
+impl ReconnectPolicySession for ObservedReconnectPolicySession {
+    fn get_delay(&self) -> Duration {
+        self.inner.get_delay()
+    }
+
+    fn on_successful_fill(&mut self) {
+        // Successful fill after a failed one means the pool has reconnected to the host
+        if self.reconnecting {
+            self.counters.reconnections.fetch_add(1, Ordering::Relaxed);
+            self.reconnecting = false;
+        }
+        self.inner.on_successful_fill();
+    }
+
+    fn on_fill_error(&mut self) {
+        self.counters
+            .failed_attempts
+            .fetch_add(1, Ordering::Relaxed);
+        self.reconnecting = true;
+        self.inner.on_fill_error();
+    }
+}
+
+impl ReconnectPolicy for NoInitialDelayReconnectPolicy {
+    fn new_session(&self) -> Box<dyn ReconnectPolicySession> {
+        Box::new(NoInitialDelayReconnectPolicySession {
+            inner: self.inner.new_session(),
+            reconnecting: false,
+            no_delay: false,
+        })
+    }
+}
+
+impl ReconnectPolicySession for NoInitialDelayReconnectPolicySession {
+    fn get_delay(&self) -> Duration {
+        if self.no_delay {
+            Duration::ZERO
+        } else {
+            self.inner.get_delay()
+        }
+    }
+
+    fn on_successful_fill(&mut self) {
+        self.reconnecting = false;
+        self.no_delay = false;
+        self.inner.on_successful_fill();
+    }
+
+    fn on_fill_error(&mut self) {
+        // First failed fill starts the reconnection, which is attempted immediately.
+        // The wrapped policy is notified about the following failures only,
+        // so that it starts the reconnection with its initial delay.
+        self.no_delay = !self.reconnecting;
+        if self.reconnecting {
+            self.inner.on_fill_error();
+        }
+        self.reconnecting = true;
+    }
+}
+
+#[napi]
+impl SessionWrapper {
+    /// Returns the number of failed connection attempts made by the connection pools,
+    /// and the number of times the pools managed to reconnect after such failures.
+    #[napi(catch_unwind)]
+    pub fn get_reconnection_metrics(&self) -> ReconnectionMetrics {
+        ReconnectionMetrics {
+            failed_attempts: self
+                .reconnection_counters
+                .failed_attempts
+                .load(Ordering::Relaxed) as i64,
+            reconnections: self
+                .reconnection_counters
+                .reconnections
+                .load(Ordering::Relaxed) as i64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, atomic::Ordering};
+    use std::time::Duration;
+
+    use scylla::policies::reconnect::{
+        ConstantReconnectPolicy, ExponentialReconnectPolicy, ReconnectPolicy,
+    };
+
+    use super::{NoInitialDelayReconnectPolicy, ObservedReconnectPolicy, ReconnectionCounters};
+
+    #[test]
+    fn observed_reconnect_policy_counts_attempts_of_all_pools() {
+        let counters = Arc::new(ReconnectionCounters::default());
+        let policy = ObservedReconnectPolicy::new(
+            Arc::new(ConstantReconnectPolicy::new(Duration::from_secs(1))),
+            Arc::clone(&counters),
+        );
+        let mut first_pool = policy.new_session();
+        let mut second_pool = policy.new_session();
+
+        // Delays are provided by the wrapped policy (with its jitter)
+        assert!(first_pool.get_delay() > Duration::ZERO);
+        assert!(first_pool.get_delay() < Duration::from_secs(2));
+
+        // Initial fill is not a reconnection
+        first_pool.on_successful_fill();
+        first_pool.on_fill_error();
+        first_pool.on_fill_error();
+        second_pool.on_fill_error();
+        first_pool.on_successful_fill();
+        first_pool.on_successful_fill();
+
+        assert_eq!(counters.failed_attempts.load(Ordering::Relaxed), 3);
+        assert_eq!(counters.reconnections.load(Ordering::Relaxed), 1);
+
+        second_pool.on_successful_fill();
+        assert_eq!(counters.reconnections.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn no_initial_delay_reconnect_policy_starts_each_reconnection_with_no_delay() {
+        let policy = NoInitialDelayReconnectPolicy {
+            inner: Arc::new(
+                ExponentialReconnectPolicy::new()
+                    .with_backoff_limits(Duration::from_secs(1), Duration::from_secs(60)),
+            ),
+        };
+        let mut pool = policy.new_session();
+
+        pool.on_successful_fill();
+        pool.on_fill_error();
+        assert_eq!(pool.get_delay(), Duration::ZERO);
+        pool.on_fill_error();
+        assert!(pool.get_delay() > Duration::ZERO);
+
+        pool.on_successful_fill();
+        pool.on_fill_error();
+        assert_eq!(pool.get_delay(), Duration::ZERO);
+    }
+
+    #[test]
+    fn no_initial_delay_reconnect_policy_keeps_the_wrapped_delays() {
+        let policy = NoInitialDelayReconnectPolicy {
+            inner: Arc::new(ConstantReconnectPolicy::new(Duration::from_secs(1))),
+        };
+        let mut pool = policy.new_session();
+
+        assert!(pool.get_delay() > Duration::ZERO);
+        pool.on_fill_error();
+        assert_eq!(pool.get_delay(), Duration::ZERO);
+        pool.on_fill_error();
+        assert!(pool.get_delay() > Duration::ZERO);
+        assert!(pool.get_delay() < Duration::from_secs(2));
+    }
+}
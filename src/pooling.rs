@@ -0,0 +1,103 @@
+use std::num::NonZeroUsize;
+use std::time::Duration;
+
+use scylla::client::PoolSize;
+use scylla::client::session_builder::SessionBuilder;
+use scylla::routing::ShardAwarePortRange;
+
+use crate::errors::{ErrorType, js_typed_error};
+use crate::utils::from_napi_obj::define_js_to_rust_convertible_object;
+
+// Range of the local ports used when connecting to the shard-aware port.
+// Both ends are inclusive.
+#[rustfmt::skip]
+define_js_to_rust_convertible_object!(PortRangeOptions {
+    min, min: u32,
+    max, max: u32
+});
+
+// Only one of the core connections options can be provided.
+// Heart beat interval and timeout are expressed in milliseconds, with 0 disabling them.
+#[rustfmt::skip]
+define_js_to_rust_convertible_object!(PoolingOptions {
+    core_connections_per_host, coreConnectionsPerHost: u32,
+    core_connections_per_shard, coreConnectionsPerShard: u32,
+    disallow_shard_aware_port, disallowShardAwarePort: bool,
+    shard_aware_local_port_range, shardAwareLocalPortRange: PortRangeOptions,
+    heart_beat_interval, heartBeatInterval: u32,
+    heart_beat_timeout, heartBeatTimeout: u32
+});
+
+impl PoolingOptions {
+    /// Applies the connection pool options to the session builder.
+    pub(crate) fn apply(&self, mut builder: SessionBuilder) -> napi::Result<SessionBuilder> {
+        if let Some(pool_size) = self.pool_size()? {
+            builder = builder.pool_size(pool_size);
+        }
+        if let Some(disallow) = self.disallow_shard_aware_port {
+            builder = builder.disallow_shard_aware_port(disallow);
+        }
+        if let Some(range) = &self.shard_aware_local_port_range {
+            builder = builder.shard_aware_local_port_range(range.to_port_range()?);
+        }
+        if let Some(interval) = self.heart_beat_interval {
+            builder.config.keepalive_interval = optional_duration(interval);
+        }
+        if let Some(timeout) = self.heart_beat_timeout {
+            builder.config.keepalive_timeout = optional_duration(timeout);
+        }
+        Ok(builder)
+    }
+
+    fn pool_size(&self) -> napi::Result<Option<PoolSize>> {
+        match (
+            self.core_connections_per_host,
+            self.core_connections_per_shard,
+        ) {
+            (Some(per_host), None) => Ok(Some(PoolSize::PerHost(connection_count(per_host)?))),
+            (None, Some(per_shard)) => Ok(Some(PoolSize::PerShard(connection_count(per_shard)?))),
+            (None, None) => Ok(None),
+            (Some(_), Some(_)) => Err(js_typed_error(
+                "Core connections cannot be provided both per host and per shard",
+                ErrorType::ArgumentError,
+            )),
+        }
+    }
+}
+
+impl PortRangeOptions {
+    fn to_port_range(&self) -> napi::Result<ShardAwarePortRange> {
+        let (Some(min), Some(max)) = (self.min, self.max) else {
+            return Err(js_typed_error(
+                "Both ends of the shard-aware local port range must be provided",
+                ErrorType::ArgumentError,
+            ));
+        };
+        let invalid_range = || {
+            js_typed_error(
+                format!(
+                    "Invalid shard-aware local port range [{min}, {max}]: \
+                    it must be non-empty and within [1024, 65535]"
+                ),
+                ErrorType::ArgumentError,
+            )
+        };
+        let min = u16::try_from(min).map_err(|_| invalid_range())?;
+        let max = u16::try_from(max).map_err(|_| invalid_range())?;
+        ShardAwarePortRange::new(min..=max).map_err(|_| invalid_range())
+    }
+}
+
+fn connection_count(count: u32) -> napi::Result<NonZeroUsize> {
+    NonZeroUsize::new(count as usize).ok_or_else(|| {
+        js_typed_error(
+            "Number of core connections must be positive",
+            ErrorType::ArgumentError,
+        )
+    })
+}
+
+/// Converts the value in milliseconds into a duration, with 0 meaning no value.
+fn optional_duration(millis: u32) -> Option<Duration> {
+    (millis > 0).then(|| Duration::from_millis(millis.into()))
+}
@@ -0,0 +1,63 @@
+use std::future::Future;
+
+use tokio::sync::watch;
+
+use crate::errors::{ErrorType, js_typed_error};
+
+/// Handle used to cancel a request that is in progress.
+///
+/// In JS, it is cancelled when the `AbortSignal` provided with the query options is aborted.
+/// Cancelling drops the future of the request, which releases the stream id of the connection.
+#[napi]
+pub struct CancellationHandle {
+    cancelled: watch::Sender<bool>,
+}
+
+#[napi]
+impl CancellationHandle {
+    #[napi(constructor, catch_unwind)]
+    pub fn new() -> Self {
+        CancellationHandle {
+            cancelled: watch::Sender::new(false),
+        }
+    }
+
+    /// Cancels all the requests executed with this handle, including the following ones.
+    #[napi(catch_unwind)]
+    pub fn cancel(&self) {
+        self.cancelled.send_replace(true);
+    }
+
+    #[napi(catch_unwind)]
+    pub fn is_cancelled(&self) -> bool {
+        *self.cancelled.borrow()
+    }
+}
+
+impl Default for CancellationHandle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Runs the request, unless it's cancelled with the provided handle.
+///
+/// Cancelled requests fail with `AbortError`.
+pub(crate) async fn with_cancellation<T>(
+    handle: Option<&CancellationHandle>,
+    request: impl Future<Output = napi::Result<T>>,
+) -> napi::Result<T> {
+    let Some(handle) = handle else {
+        return request.await;
+    };
+    let mut cancelled = handle.cancelled.subscribe();
+    tokio::select! {
+        // Check the cancellation first, so that already cancelled requests are never started
+        biased;
+        _ = cancelled.wait_for(|cancelled| *cancelled) => Err(js_typed_error(
+            "The request was aborted",
+            ErrorType::AbortError,
+        )),
+        result = request => result,
+    }
+}
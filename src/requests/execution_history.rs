@@ -0,0 +1,164 @@
+use std::net::SocketAddr;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use scylla::client::execution_profile::ExecutionProfileHandle;
+use scylla::client::session::Session;
+use scylla::errors::{RequestAttemptError, RequestError};
+use scylla::observability::history::{AttemptId, HistoryListener, RequestId, SpeculativeId};
+use scylla::policies::retry::RetryDecision;
+use scylla::statement::Consistency;
+
+/// Listener collecting the execution history of a single request.
+///
+/// A new listener should be set on the statement for each request,
+/// created with the consistency the statement is executed with.
+/// Unlike `HistoryCollector` from the Rust driver, it doesn't keep the events,
+/// but only the summary that is exposed in `ResultSet.info`.
+#[derive(Debug)]
+pub(crate) struct ExecutionHistory {
+    state: Mutex<ExecutionSummary>,
+}
+
+/// Summary of the execution history of a request.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct ExecutionSummary {
+    /// Nodes to which the attempts were sent, with the number of attempts,
+    /// in the order of the first attempt sent to each of them.
+    pub(crate) tried_hosts: Vec<(SocketAddr, u32)>,
+    /// Number of speculative executions, not including the first execution.
+    pub(crate) speculative_executions: u32,
+    /// Consistency the statement is executed with, unless the retry policy changed it when retrying.
+    pub(crate) consistency: Option<Consistency>,
+    started: Option<Instant>,
+    finished: Option<Instant>,
+}
+
+impl ExecutionHistory {
+    pub(crate) fn new(consistency: Consistency) -> Self {
+        ExecutionHistory {
+            state: Mutex::new(ExecutionSummary {
+                consistency: Some(consistency),
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Creates the listener for the statement with the provided consistency and execution profile.
+    ///
+    /// Statements without the consistency are executed with the consistency of their execution profile,
+    /// or of the default execution profile of the session.
+    pub(crate) fn for_statement(
+        session: &Session,
+        consistency: Option<Consistency>,
+        profile: Option<&ExecutionProfileHandle>,
+    ) -> Self {
+        ExecutionHistory::new(consistency.unwrap_or_else(|| {
+            profile
+                .unwrap_or_else(|| session.get_default_execution_profile_handle())
+                .to_profile()
+                .get_consistency()
+        }))
+    }
+
+    /// Returns the summary of the events received so far.
+    pub(crate) fn summary(&self) -> ExecutionSummary {
+        self.state().clone()
+    }
+
+    // Events are only counted, so the state is valid even if another thread panicked.
+    fn state(&self) -> MutexGuard<'_, ExecutionSummary> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl ExecutionSummary {
+    /// Time between the start of the request and its completion, if it has completed.
+    pub(crate) fn latency(&self) -> Option<Duration> {
+        Some(self.finished?.duration_since(self.started?))
+    }
+}
+
+impl HistoryListener for ExecutionHistory {
+    fn log_request_start(&self) -> RequestId {
+        self.state().started = Some(Instant::now());
+        RequestId(0)
+    }
+
+    fn log_request_success(&self, _request_id: RequestId) {
+        self.state().finished = Some(Instant::now());
+    }
+
+    fn log_request_error(&self, _request_id: RequestId, _error: &RequestError) {
+        self.state().finished = Some(Instant::now());
+    }
+
+    fn log_new_speculative_fiber(&self, _request_id: RequestId) -> SpeculativeId {
+        let mut state = self.state();
+        state.speculative_executions += 1;
+        SpeculativeId(state.speculative_executions as usize)
+    }
+
+    fn log_attempt_start(
+        &self,
+        _request_id: RequestId,
+        _speculative_id: Option<SpeculativeId>,
+        node_addr: SocketAddr,
+    ) -> AttemptId {
+        let mut state = self.state();
+        match state
+            .tried_hosts
+            .iter_mut()
+            .find(|(address, _)| *address == node_addr)
+        {
+            Some((_, attempts)) => *attempts += 1,
+            None => state.tried_hosts.push((node_addr, 1)),
+        }
+        AttemptId(0)
+    }
+
+    fn log_attempt_success(&self, _attempt_id: AttemptId) {}
+
+    fn log_attempt_error(
+        &self,
+        _attempt_id: AttemptId,
+        _error: &RequestAttemptError,
+        retry_decision: &RetryDecision,
+    ) {
+        if let RetryDecision::RetrySameTarget(Some(consistency))
+        | RetryDecision::RetryNextTarget(Some(consistency)) = retry_decision
+        {
+            self.state().consistency = Some(*consistency);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_attempts_per_host() {
+        let history = ExecutionHistory::new(Consistency::Quorum);
+        let first: SocketAddr = "127.0.0.1:9042".parse().unwrap();
+        let second: SocketAddr = "127.0.0.2:9042".parse().unwrap();
+
+        let request = history.log_request_start();
+        let attempt = history.log_attempt_start(request, None, first);
+        history.log_attempt_error(
+            attempt,
+            &RequestAttemptError::RepreparedIdMissingInBatch,
+            &RetryDecision::RetryNextTarget(Some(Consistency::One)),
+        );
+        let speculative = history.log_new_speculative_fiber(request);
+        history.log_attempt_start(request, Some(speculative), second);
+        history.log_attempt_start(request, None, first);
+        history.log_request_success(request);
+
+        let summary = history.summary();
+        assert_eq!(summary.tried_hosts, vec![(first, 2), (second, 1)]);
+        assert_eq!(summary.speculative_executions, 1);
+        assert_eq!(summary.consistency, Some(Consistency::One));
+        assert!(summary.latency().is_some());
+    }
+}
@@ -1 +1,3 @@
+pub mod cancellation;
+pub mod execution_history;
 pub mod request;
@@ -1,8 +1,11 @@
 use napi::bindgen_prelude::BigInt;
 use scylla::statement::prepared::PreparedStatement;
+use uuid::Uuid;
 
 use crate::{
-    types::type_wrappers::ComplexType, utils::from_napi_obj::define_js_to_rust_convertible_object,
+    errors::{ErrorType, js_typed_error},
+    types::type_wrappers::ComplexType,
+    utils::from_napi_obj::define_js_to_rust_convertible_object,
 };
 
 pub(crate) struct PreparedStatementWrapper {
@@ -13,7 +16,6 @@ pub(crate) struct PreparedStatementWrapper {
 // customPayload?, any;
 // executionProfile?, string | ExecutionProfile;
 // hints?, string[] | string[][];
-// host?, Host;
 // pageState?, Buffer | string;
 // retry?, policies.retry.RetryPolicy;
 // routingKey?, Buffer | Buffer[];
@@ -24,6 +26,7 @@ define_js_to_rust_convertible_object!(
         consistency, consistency: u16,
         counter, counter: bool,
         fetch_size, fetchSize: i32,
+        host_id, hostId: String,
         is_idempotent, isIdempotent: bool,
         keyspace, keyspace: String,
         logged, logged: bool,
@@ -32,11 +35,35 @@ define_js_to_rust_convertible_object!(
         routing_indexes, routingIndexes: Vec<i32>,
         routing_names, routingNames: Vec<String>,
         serial_consistency, serialConsistency: i16,
+        shard, shard: u32,
         timestamp, timestamp: BigInt,
         trace_query, traceQuery: bool
     }
 );
 
+impl QueryOptionsObj {
+    /// Get the host ID of the node that should handle the query, if it was provided.
+    pub(crate) fn target_host_id(&self) -> napi::Result<Option<Uuid>> {
+        if self.shard.is_some() && self.host_id.is_none() {
+            return Err(js_typed_error(
+                "Shard can be provided only together with the target host",
+                ErrorType::ArgumentError,
+            ));
+        }
+        self.host_id
+            .as_ref()
+            .map(|host_id| {
+                Uuid::parse_str(host_id).map_err(|_| {
+                    js_typed_error(
+                        "Invalid host id of the target host",
+                        ErrorType::ArgumentError,
+                    )
+                })
+            })
+            .transpose()
+    }
+}
+
 #[napi]
 pub struct QueryOptionsWrapper {
     pub(crate) options: QueryOptionsObj,
@@ -44,7 +71,7 @@ pub struct QueryOptionsWrapper {
 
 #[napi]
 impl QueryOptionsWrapper {
-    #[napi(constructor)]
+    #[napi(constructor, catch_unwind)]
     pub fn new(options: QueryOptionsObj) -> Self {
         QueryOptionsWrapper { options }
     }
@@ -1,16 +1,34 @@
 use crate::{
-    errors::err_to_napi,
+    arrow,
+    errors::{ErrorType, err_to_napi, js_typed_error},
+    requests::execution_history::{ExecutionHistory, ExecutionSummary},
     types::{local_date::LocalDateWrapper, type_wrappers::ComplexType, uuid::UuidWrapper},
+    utils::to_napi_obj::define_rust_to_js_convertible_object,
 };
 use napi::{
-    Env, JsValue, Result,
-    bindgen_prelude::{BigInt, Buffer, JsObjectValue, Object, ToNapiValue},
+    Env, JsValue, Property, PropertyAttributes, Result, Unknown, ValueType,
+    bindgen_prelude::{
+        Array, BigInt, Buffer, BufferSlice, Float32Array, Float64Array, Function, Int32Array,
+        JsObjectValue, Null, Object, ToNapiValue,
+    },
 };
 use scylla::{
-    errors::IntoRowsResultError,
-    frame::response::result::ColumnSpec,
-    response::query_result::{QueryResult, QueryRowsResult},
-    value::{CqlValue, Row},
+    deserialize::result::TypedRowIterator,
+    deserialize::{
+        FrameSlice,
+        row::ColumnIterator,
+        value::{
+            DeserializeValue, FrameSliceWithMetadata, ListlikeIterator, MapIterator, UdtIterator,
+            VectorIterator,
+        },
+    },
+    errors::{DeserializationError, IntoRowsResultError},
+    frame::response::result::{CollectionType, ColumnSpec, ColumnType, NativeType},
+    response::{
+        Coordinator,
+        query_result::{QueryResult, QueryRowsResult},
+    },
+    value::{Counter, CqlDecimalBorrowed, CqlTimestamp, CqlTimeuuid, CqlVarintBorrowed},
 };
 
 use crate::types::duration::DurationWrapper;
@@ -26,30 +44,93 @@ enum QueryResultVariant {
 #[napi]
 pub struct QueryResultWrapper {
     inner: QueryResultVariant,
+    execution: ExecutionSummary,
 }
 
-/// Wrapper for a single row of the query result
+/// Wrapper for a single CQL value, borrowed from the response frame
 ///
-/// Whenever it's returned from NAPI function call
-/// it's automatically converted to the inner vector
-pub struct RowWrapper {
-    inner: Vec<Option<CqlValue>>,
+/// When returned from NAPI-RS, the value is deserialized directly into the JS value,
+/// without building the intermediate ``CqlValue``. This way strings, blobs and elements
+/// of the collections are copied only once, from the frame bytes into the JS memory.
+///
+/// Plain value is returned, based on the value type. Some of the types are returned
+/// in the same representation, and are differentiated on the JS side based on the column type.
+/// For example CqlBigInt and CqlCounter are both returned from rust layer
+/// as BigInt, but CqlBigInt is provided to driver's user as Long.
+/// CqlTimestamp is returned in the representation selected by the decoding options.
+pub struct CqlValueWrapper<'frame, 'metadata, 'env> {
+    pub(crate) typ: &'metadata ColumnType<'metadata>,
+    pub(crate) slice: Option<FrameSlice<'frame>>,
+    pub(crate) options: DecodingOptions<'env>,
 }
 
-/// Wrapper for a single CQL value
-///
-/// When returned from NAPI-RS, returns either just a inner value or tuple of elements: ``(cqlType, value)``
-///
-/// Plain value is returned, when we know how to treat the value, based on the value type.
-/// The tuple is returned when there is ambiguity how the given value
-/// should be handled, based on its type.
+/// Options of converting the CQL values into JS values, shared by all the values of the result
+#[derive(Clone, Copy, Default)]
+pub(crate) struct DecodingOptions<'env> {
+    /// Constructors of the user defined types, keyed by the `keyspace.type_name`.
+    /// Values of the types without a constructor are created as plain objects.
+    pub(crate) udt_constructors: Option<Object<'env>>,
+    pub(crate) timestamp_decoding: TimestampDecoding,
+}
+
+/// JS representation of the CQL timestamp values
+#[napi]
+#[derive(Clone, Copy, Debug, Default)]
+pub enum TimestampDecoding {
+    /// Date object. Timestamps outside of the range supported by Date result in invalid dates.
+    #[default]
+    Date,
+    /// Number of milliseconds since the epoch. Precision is lost for timestamps above 2^53 milliseconds.
+    Number,
+    /// BigInt number of milliseconds since the epoch, representing all the timestamps exactly.
+    BigInt,
+}
+
+// Number of attempts sent to a single host during the execution of the request.
+define_rust_to_js_convertible_object!(TriedHost {
+    address,
+    address: String,
+    attempts,
+    attempts: u32
+});
+
+// Information required in the ResultSet.info field.
+// Latency is expressed in milliseconds.
+define_rust_to_js_convertible_object!(ExecutionInfo {
+    queried_host,
+    queriedHost: String,
+    queried_host_id,
+    queriedHostId: String,
+    tried_hosts,
+    triedHosts: Vec<TriedHost>,
+    speculative_executions,
+    speculativeExecutions: u32,
+    achieved_consistency,
+    achievedConsistency: Option<u16>,
+    latency,
+    latency: Option<f64>,
+    trace_id,
+    traceId: Option<UuidWrapper>,
+    warnings,
+    warnings: Vec<String>
+});
+
+/// Name of the first column in the result of a conditional update.
+const APPLIED_COLUMN: &str = "[applied]";
+
+/// Checks whether the column is the `[applied]` column of the conditional update result.
+fn is_applied_column(spec: &ColumnSpec) -> bool {
+    spec.name() == APPLIED_COLUMN && matches!(spec.typ(), ColumnType::Native(NativeType::Boolean))
+}
+
+/// Reads the value of the `[applied]` column.
 ///
-/// For example if we return String, no matter if it's CqlAscii or CqlText,
-/// we treat it the same, and don't need to specify CqlType.
-/// On the other hand, CqlBigInt and CqlTimestamp are returned from rust layer
-/// as BigInt, but are provided to driver's user as BigInt and Date, respectively.
-pub struct CqlValueWrapper {
-    pub(crate) inner: CqlValue,
+/// Without the value, the update is considered as applied.
+fn is_applied(
+    typ: &ColumnType,
+    slice: Option<FrameSlice>,
+) -> std::result::Result<bool, DeserializationError> {
+    Ok(<Option<bool>>::deserialize(typ, slice)?.unwrap_or(true))
 }
 
 /// Wrapper for the information required in the ResultSet.columns field
@@ -63,7 +144,12 @@ pub struct MetaColumnWrapper {
 #[napi]
 impl QueryResultWrapper {
     /// Converts rust query result into query result wrapper that can be passed to NAPI-RS
-    pub fn from_query(result: QueryResult) -> napi::Result<QueryResultWrapper> {
+    ///
+    /// The history should be the one set as the history listener of the executed statement.
+    pub(crate) fn from_query(
+        result: QueryResult,
+        history: &ExecutionHistory,
+    ) -> napi::Result<QueryResultWrapper> {
         let value = match result.into_rows_result() {
             Ok(v) => QueryResultVariant::RowsResult(v),
             Err(IntoRowsResultError::ResultNotRows(v)) => QueryResultVariant::EmptyResult(v),
@@ -71,12 +157,33 @@ impl QueryResultWrapper {
                 return Err(err_to_napi(e));
             }
         };
-        Ok(QueryResultWrapper { inner: value })
+        Ok(QueryResultWrapper {
+            inner: value,
+            execution: history.summary(),
+        })
     }
 
-    /// Extracts all the rows of the result into a vector of rows
-    #[napi]
-    pub fn get_rows(&self) -> napi::Result<Option<Vec<RowWrapper>>> {
+    /// Extracts all the rows of the result into an array of JS objects,
+    /// with the values of the columns set under the column names.
+    ///
+    /// Each row is created with the provided constructor, called with the array of the column names.
+    /// The column names are converted into JS strings once per result, and shared by all the rows.
+    ///
+    /// Values of the user defined types are created with the constructors from the provided object,
+    /// keyed by the `keyspace.type_name`, or as plain objects for the types without a constructor.
+    /// Timestamps are created as Date objects, unless other representation is provided.
+    #[napi(catch_unwind)]
+    pub fn get_rows_as_objects<'env>(
+        &self,
+        env: &'env Env,
+        row_constructor: Function<'env, Array<'env>, Unknown<'env>>,
+        udt_constructors: Option<Object<'env>>,
+        timestamp_decoding: Option<TimestampDecoding>,
+    ) -> napi::Result<Option<Array<'env>>> {
+        let options = DecodingOptions {
+            udt_constructors,
+            timestamp_decoding: timestamp_decoding.unwrap_or_default(),
+        };
         let result = match &self.inner {
             QueryResultVariant::RowsResult(v) => v,
             QueryResultVariant::EmptyResult(_) => {
@@ -84,20 +191,62 @@ impl QueryResultWrapper {
             }
         };
 
-        let rows = result.rows::<Row>()
-            .expect("Type check against the Row type has failed; this is a bug in the underlying Rust driver");
+        let names = result
+            .column_specs()
+            .iter()
+            .map(|spec| spec.name())
+            .collect::<Vec<_>>();
+        let rows = column_rows(result)?;
+        let rows_count = rows.rows_remaining();
+        let rows = rows.map(|row| {
+            row.map_err(err_to_napi).map(|row| {
+                row.map(|column| {
+                    column
+                        .map(|column| (column.spec.typ(), column.slice))
+                        .map_err(err_to_napi)
+                })
+            })
+        });
+        create_row_objects(env, &names, rows_count, rows, &row_constructor, options).map(Some)
+    }
+
+    /// Converts the rows of the result into Arrow IPC stream,
+    /// with the schema followed by a single record batch.
+    ///
+    /// Returns None for the results of the statements that do not return rows.
+    #[napi(catch_unwind)]
+    pub fn to_arrow_ipc(&self) -> napi::Result<Option<Buffer>> {
+        self.to_arrow_ipc_page(true, true)
+    }
 
+    /// Converts the rows of the result, being a single page of the query result,
+    /// into a part of the Arrow IPC stream.
+    ///
+    /// The schema should be included only for the first page, and the end of stream marker
+    /// only for the last one. Parts created for all the pages, concatenated together,
+    /// make a valid Arrow IPC stream.
+    #[napi(catch_unwind)]
+    pub fn to_arrow_ipc_page(
+        &self,
+        include_schema: bool,
+        end_of_stream: bool,
+    ) -> napi::Result<Option<Buffer>> {
+        let result = match &self.inner {
+            QueryResultVariant::RowsResult(v) => v,
+            QueryResultVariant::EmptyResult(_) => {
+                return Ok(None);
+            }
+        };
+        let batch = arrow::to_record_batch(result)?;
         Ok(Some(
-            rows.map(|f| {
-                f.map(|v| RowWrapper { inner: v.columns })
-                    .map_err(err_to_napi)
-            })
-            .collect::<Result<Vec<_>, _>>()?,
+            arrow::write_ipc(&batch, include_schema, end_of_stream)
+                .map_err(err_to_napi)?
+                .into(),
         ))
     }
 
     /// Get the names of the columns in order, as they appear in the query result
-    #[napi]
+    #[napi(catch_unwind)]
     pub fn get_columns_names(&self) -> Vec<String> {
         match &self.inner {
             QueryResultVariant::RowsResult(v) => v,
@@ -112,7 +261,7 @@ impl QueryResultWrapper {
     }
 
     /// Get the names of the columns in order, as they appear in the query result
-    #[napi]
+    #[napi(catch_unwind)]
     pub fn get_columns_types(&self) -> Vec<ComplexType<'_>> {
         match &self.inner {
             QueryResultVariant::RowsResult(v) => v,
@@ -127,7 +276,7 @@ impl QueryResultWrapper {
     }
 
     /// Get the specification of all columns as they appear in the query result
-    #[napi]
+    #[napi(catch_unwind)]
     pub fn get_columns_specs(&self) -> Vec<MetaColumnWrapper> {
         match &self.inner {
             QueryResultVariant::RowsResult(v) => v,
@@ -145,55 +294,243 @@ impl QueryResultWrapper {
         .collect()
     }
 
-    /// Get all warnings generated in the query
-    #[napi]
-    pub fn get_warnings(&self) -> Vec<String> {
-        match &self.inner {
-            QueryResultVariant::RowsResult(v) => v.warnings().map(|e| e.to_owned()).collect(),
-            QueryResultVariant::EmptyResult(v) => v.warnings().map(|e| e.to_owned()).collect(),
+    /// Checks whether the conditional update (lightweight transaction) was applied.
+    ///
+    /// Result of a conditional update has a single row, with the `[applied]` column first,
+    /// followed by the existing values of the row when the update was not applied.
+    /// Results of other statements are always considered as applied.
+    #[napi(catch_unwind)]
+    pub fn was_applied(&self) -> napi::Result<bool> {
+        let QueryResultVariant::RowsResult(result) = &self.inner else {
+            return Ok(true);
+        };
+        if !result
+            .column_specs()
+            .get_by_index(0)
+            .is_some_and(is_applied_column)
+        {
+            return Ok(true);
+        }
+        // Only the first column of the first row is deserialized.
+        let first_row = result
+            .maybe_first_row::<ColumnIterator>()
+            .map_err(err_to_napi)?;
+        match first_row.and_then(|mut columns| columns.next()) {
+            Some(column) => {
+                let column = column.map_err(err_to_napi)?;
+                is_applied(column.spec.typ(), column.slice).map_err(err_to_napi)
+            }
+            None => Ok(true),
         }
     }
 
-    /// Get all tracing ids generated in the query
-    #[napi]
-    pub fn get_trace_id(&self) -> Option<UuidWrapper> {
-        match &self.inner {
-            QueryResultVariant::RowsResult(v) => v.tracing_id().map(UuidWrapper::from_cql_uuid),
-            QueryResultVariant::EmptyResult(v) => v.tracing_id().map(UuidWrapper::from_cql_uuid),
+    /// Get the information on the execution of the query: the coordinator,
+    /// the hosts tried before receiving the response, the tracing id and the warnings.
+    ///
+    /// Tried hosts are identified by the address of the connection used for the attempt,
+    /// so the port may be the shard-aware one.
+    #[napi(catch_unwind)]
+    pub fn get_info(&self) -> ExecutionInfo {
+        let (coordinator, tracing_id, warnings): (&Coordinator, _, Vec<String>) = match &self.inner
+        {
+            QueryResultVariant::RowsResult(v) => (
+                v.request_coordinator(),
+                v.tracing_id(),
+                v.warnings().map(|e| e.to_owned()).collect(),
+            ),
+            QueryResultVariant::EmptyResult(v) => (
+                v.request_coordinator(),
+                v.tracing_id(),
+                v.warnings().map(|e| e.to_owned()).collect(),
+            ),
+        };
+        ExecutionInfo {
+            queried_host: coordinator.node().address.to_string(),
+            queried_host_id: coordinator.node().host_id.to_string(),
+            tried_hosts: self
+                .execution
+                .tried_hosts
+                .iter()
+                .map(|(address, attempts)| TriedHost {
+                    address: address.to_string(),
+                    attempts: *attempts,
+                })
+                .collect(),
+            speculative_executions: self.execution.speculative_executions,
+            achieved_consistency: self.execution.consistency.map(|c| c as u16),
+            latency: self
+                .execution
+                .latency()
+                .map(|latency| latency.as_secs_f64() * 1000.0),
+            trace_id: tracing_id.map(UuidWrapper::from_cql_uuid),
+            warnings,
         }
     }
 }
 
-impl ToNapiValue for RowWrapper {
-    /// # Safety
-    ///
-    /// Valid pointer to napi env must be provided
-    unsafe fn to_napi_value(
-        env: napi::sys::napi_env,
-        val: Self,
-    ) -> napi::Result<napi::sys::napi_value> {
-        // Caller of this function ensures a valid pointer to napi env is provided
-        unsafe {
-            Vec::to_napi_value(
-                env,
-                val.inner
-                    .into_iter()
-                    .map(|e| e.map(|f| CqlValueWrapper { inner: f }))
-                    .collect(),
-            )
+/// Creates the JS objects of the rows, with the values of the columns set under the column names.
+///
+/// The column names are converted into JS strings once, and the same array of the names
+/// is provided to the constructor of each row.
+pub(crate) fn create_row_objects<'env, 'frame, 'metadata, R, C>(
+    env: &'env Env,
+    column_names: &[&str],
+    rows_count: usize,
+    rows: R,
+    row_constructor: &Function<'env, Array<'env>, Unknown<'env>>,
+    options: DecodingOptions<'env>,
+) -> Result<Array<'env>>
+where
+    R: Iterator<Item = Result<C>>,
+    C: Iterator<Item = Result<(&'metadata ColumnType<'metadata>, Option<FrameSlice<'frame>>)>>,
+{
+    let names = column_names
+        .iter()
+        .map(|name| env.create_string(name))
+        .collect::<Result<Vec<_>>>()?;
+    let mut names_array = env.create_array(names.len() as u32)?;
+    for (index, name) in (0..).zip(&names) {
+        names_array.set(index, *name)?;
+    }
+
+    let mut rows_array = env.create_array(rows_count as u32)?;
+    for (index, row) in (0..).zip(rows) {
+        let row = row?;
+        // The constructor is expected to return an object
+        let mut row_object: Object = unsafe { row_constructor.new_instance(names_array)?.cast() }?;
+        for (name, column) in names.iter().zip(row) {
+            let (typ, slice) = column?;
+            // The env is provided by NAPI-RS, so it's valid for the duration of this call
+            let value = unsafe {
+                Unknown::from_raw_unchecked(
+                    env.raw(),
+                    CqlValueWrapper::to_napi_value(
+                        env.raw(),
+                        CqlValueWrapper {
+                            typ,
+                            slice,
+                            options,
+                        },
+                    )?,
+                )
+            };
+            row_object.set_property(*name, value)?;
         }
+        rows_array.set(index, row_object)?;
     }
+    Ok(rows_array)
 }
 
-impl From<Row> for RowWrapper {
-    fn from(value: Row) -> Self {
-        RowWrapper {
-            inner: value.columns,
+impl<'env> DecodingOptions<'env> {
+    /// Wraps the value nested in another value, to be converted with the same options
+    fn wrap<'frame, 'metadata>(
+        self,
+        value: FrameSliceWithMetadata<'frame, 'metadata>,
+    ) -> CqlValueWrapper<'frame, 'metadata, 'env> {
+        CqlValueWrapper {
+            typ: value.column_type,
+            slice: value.frame_slice,
+            options: self,
+        }
+    }
+
+    /// Creates the object for the value of the user defined type with the given `keyspace.type_name`,
+    /// with the constructor registered for the type, or as a plain object
+    fn create_udt_object<'a>(self, env: &'a Env, udt_name: &str) -> Result<Object<'a>> {
+        let Some(constructors) = self.udt_constructors else {
+            return Object::new(env);
+        };
+        let constructor: Unknown = constructors.get_named_property_unchecked(udt_name)?;
+        match constructor.get_type()? {
+            ValueType::Undefined => Object::new(env),
+            // Type of the constructor is checked above, and calling it with `new` always creates an object
+            ValueType::Function => unsafe {
+                constructor
+                    .cast::<Function<(), Unknown>>()?
+                    .new_instance(())?
+                    .cast()
+            },
+            _ => Err(js_typed_error(
+                format!("Constructor of the user defined type {udt_name} must be a function"),
+                ErrorType::TypeError,
+            )),
         }
     }
 }
 
-impl ToNapiValue for CqlValueWrapper {
+/// Creates JS array from the given elements, converting each of them into JS value
+fn to_js_array<T: ToNapiValue>(
+    env: &Env,
+    elements: impl ExactSizeIterator<Item = Result<T>>,
+) -> Result<napi::sys::napi_value> {
+    let mut array = env.create_array(elements.len() as u32)?;
+    for (index, element) in (0..).zip(elements) {
+        array.set(index, element?)?;
+    }
+    Ok(array.raw())
+}
+
+/// Returns an iterator over the rows of the result, with the columns not yet deserialized
+pub(crate) fn column_rows(
+    result: &QueryRowsResult,
+) -> Result<TypedRowIterator<'_, '_, ColumnIterator<'_, '_>>> {
+    result.rows::<ColumnIterator>().map_err(|e| {
+        js_typed_error(
+            format!("Type check against the ColumnIterator type has failed; this is a bug in the underlying Rust driver: {e}"),
+            ErrorType::DriverInternalError,
+        )
+    })
+}
+
+/// Checks that the value is not empty, unless its type allows empty values
+pub(crate) fn ensure_not_empty(typ: &ColumnType, slice: &FrameSlice) -> Result<()> {
+    if slice.is_empty()
+        && !matches!(
+            typ,
+            ColumnType::Native(NativeType::Ascii | NativeType::Blob | NativeType::Text)
+        )
+    {
+        return Err(js_typed_error(
+            "Empty values are not supported",
+            ErrorType::NotSupportedError,
+        ));
+    }
+    Ok(())
+}
+
+/// Decodes vector of fixed-size numeric elements, stored in big endian, into native values
+fn decode_numeric_vector<T, const N: usize>(
+    slice: FrameSlice,
+    dimensions: u16,
+    from_be_bytes: fn([u8; N]) -> T,
+) -> Result<Vec<T>> {
+    let bytes = slice.as_slice();
+    if bytes.len() != N * dimensions as usize {
+        return Err(js_typed_error(
+            format!(
+                "Expected {} bytes for vector of {dimensions} elements, got {}",
+                N * dimensions as usize,
+                bytes.len()
+            ),
+            ErrorType::TypeError,
+        ));
+    }
+    Ok(bytes
+        .chunks_exact(N)
+        // chunks_exact guarantees each chunk has exactly N bytes
+        .map(|chunk| from_be_bytes(chunk.try_into().unwrap()))
+        .collect())
+}
+
+/// Deserializes the value of the given type from the frame slice
+pub(crate) fn deserialize<'frame, 'metadata, T: DeserializeValue<'frame, 'metadata>>(
+    typ: &'metadata ColumnType<'metadata>,
+    slice: FrameSlice<'frame>,
+) -> Result<T> {
+    T::deserialize(typ, Some(slice)).map_err(err_to_napi)
+}
+
+impl ToNapiValue for CqlValueWrapper<'_, '_, '_> {
     /// # Safety
     ///
     /// Valid pointer to napi env must be provided
@@ -201,139 +538,258 @@ impl ToNapiValue for CqlValueWrapper {
         env: napi::sys::napi_env,
         value: Self,
     ) -> napi::Result<napi::sys::napi_value> {
+        let Some(slice) = value.slice else {
+            // Caller of this function ensures a valid pointer to napi env is provided
+            return unsafe { Null::to_napi_value(env, Null) };
+        };
+        let typ = value.typ;
+        ensure_not_empty(typ, &slice)?;
+        let env_obj = Env::from_raw(env);
+
         // Caller of this function ensures a valid pointer to napi env is provided
         unsafe {
-            match value.inner {
-                CqlValue::Ascii(val) => String::to_napi_value(env, val),
-                CqlValue::Boolean(val) => bool::to_napi_value(env, val),
-                CqlValue::Blob(val) => Buffer::to_napi_value(env, val.into()),
-                CqlValue::Counter(val) => BigInt::to_napi_value(env, val.0.into()),
-                CqlValue::Decimal(val) => {
-                    const EXP_SIZE: usize = 4;
-                    // JS driver expects decimal to be in the format of Decimal from CQL protocol.
-                    // Returned buffer is in format XXXXYYYY...YYY
-                    // where XXXX (4 bytes) is the exponent of the decimal in big endian
-                    // and YYYY...YYY is the value of the decimal in big endian.
-                    let (value, len) = val.as_signed_be_bytes_slice_and_exponent();
-                    let mut buf = vec![0u8; EXP_SIZE + value.len()];
-                    buf[0..EXP_SIZE].copy_from_slice(&len.to_be_bytes());
-                    buf[EXP_SIZE..].copy_from_slice(value);
-                    Buffer::to_napi_value(env, Buffer::from(buf))
-                }
-                CqlValue::Date(val) => {
-                    LocalDateWrapper::to_napi_value(env, LocalDateWrapper::from_cql_date(val))
-                }
-                CqlValue::Double(val) => f64::to_napi_value(env, val),
-                CqlValue::Duration(val) => {
-                    DurationWrapper::to_napi_value(env, DurationWrapper::from_cql_duration(val))
-                }
-                CqlValue::Empty => todo!(),
-                CqlValue::Float(val) => f32::to_napi_value(env, val),
-                CqlValue::Int(val) => i32::to_napi_value(env, val),
-                CqlValue::BigInt(val) => BigInt::to_napi_value(env, val.into()),
-                CqlValue::Text(val) => String::to_napi_value(env, val),
-                CqlValue::Timestamp(val) => BigInt::to_napi_value(env, val.0.into()),
-                CqlValue::Inet(val) => {
-                    InetAddressWrapper::to_napi_value(env, InetAddressWrapper::from_ip_addr(val))
+            match typ {
+                ColumnType::Native(native_type) => match native_type {
+                    NativeType::Ascii | NativeType::Text => {
+                        <&str>::to_napi_value(env, deserialize(typ, slice)?)
+                    }
+                    NativeType::Boolean => bool::to_napi_value(env, deserialize(typ, slice)?),
+                    NativeType::Blob => {
+                        Ok(BufferSlice::copy_from(&env_obj, slice.as_slice())?.raw())
+                    }
+                    NativeType::Counter => {
+                        let val: Counter = deserialize(typ, slice)?;
+                        BigInt::to_napi_value(env, val.0.into())
+                    }
+                    NativeType::Decimal => {
+                        const EXP_SIZE: usize = 4;
+                        // JS driver expects decimal to be in the format of Decimal from CQL protocol.
+                        // Returned buffer is in format XXXXYYYY...YYY
+                        // where XXXX (4 bytes) is the exponent of the decimal in big endian
+                        // and YYYY...YYY is the value of the decimal in big endian.
+                        let val: CqlDecimalBorrowed = deserialize(typ, slice)?;
+                        let (value, len) = val.as_signed_be_bytes_slice_and_exponent();
+                        let mut buf = vec![0u8; EXP_SIZE + value.len()];
+                        buf[0..EXP_SIZE].copy_from_slice(&len.to_be_bytes());
+                        buf[EXP_SIZE..].copy_from_slice(value);
+                        Buffer::to_napi_value(env, Buffer::from(buf))
+                    }
+                    NativeType::Date => LocalDateWrapper::to_napi_value(
+                        env,
+                        LocalDateWrapper::from_cql_date(deserialize(typ, slice)?),
+                    ),
+                    NativeType::Double => f64::to_napi_value(env, deserialize(typ, slice)?),
+                    NativeType::Duration => DurationWrapper::to_napi_value(
+                        env,
+                        DurationWrapper::from_cql_duration(deserialize(typ, slice)?),
+                    ),
+                    NativeType::Float => f32::to_napi_value(env, deserialize(typ, slice)?),
+                    NativeType::Int => i32::to_napi_value(env, deserialize(typ, slice)?),
+                    NativeType::BigInt => {
+                        let val: i64 = deserialize(typ, slice)?;
+                        BigInt::to_napi_value(env, val.into())
+                    }
+                    NativeType::Timestamp => {
+                        let val: CqlTimestamp = deserialize(typ, slice)?;
+                        match value.options.timestamp_decoding {
+                            TimestampDecoding::Date => Ok(env_obj.create_date(val.0 as f64)?.raw()),
+                            TimestampDecoding::Number => f64::to_napi_value(env, val.0 as f64),
+                            TimestampDecoding::BigInt => BigInt::to_napi_value(env, val.0.into()),
+                        }
+                    }
+                    NativeType::Inet => InetAddressWrapper::to_napi_value(
+                        env,
+                        InetAddressWrapper::from_ip_addr(deserialize(typ, slice)?),
+                    ),
+                    NativeType::SmallInt => i16::to_napi_value(env, deserialize(typ, slice)?),
+                    NativeType::TinyInt => i8::to_napi_value(env, deserialize(typ, slice)?),
+                    NativeType::Time => LocalTimeWrapper::to_napi_value(
+                        env,
+                        LocalTimeWrapper::from_cql_time(deserialize(typ, slice)?),
+                    ),
+                    NativeType::Timeuuid => {
+                        let val: CqlTimeuuid = deserialize(typ, slice)?;
+                        Ok(BufferSlice::copy_from(&env_obj, val.as_bytes())?.raw())
+                    }
+                    NativeType::Uuid => {
+                        let val: uuid::Uuid = deserialize(typ, slice)?;
+                        Ok(BufferSlice::copy_from(&env_obj, val.as_bytes())?.raw())
+                    }
+                    NativeType::Varint => {
+                        let val: CqlVarintBorrowed = deserialize(typ, slice)?;
+                        let (sign, words) = num_bigint::BigInt::from_signed_bytes_be(
+                            val.as_signed_bytes_be_slice(),
+                        )
+                        .to_u64_digits();
+
+                        BigInt::to_napi_value(
+                            env,
+                            BigInt {
+                                sign_bit: sign == num_bigint::Sign::Minus,
+                                words,
+                            },
+                        )
+                    }
+                    other => Err(js_typed_error(
+                        format!("Missing implementation for CQL native type {other:?}"),
+                        ErrorType::NotSupportedError,
+                    )),
+                },
+                ColumnType::Collection {
+                    typ: CollectionType::List(_) | CollectionType::Set(_),
+                    ..
+                } => {
+                    let elements: ListlikeIterator<FrameSliceWithMetadata> =
+                        deserialize(typ, slice)?;
+                    to_js_array(
+                        &env_obj,
+                        elements.map(|e| e.map(|e| value.options.wrap(e)).map_err(err_to_napi)),
+                    )
                 }
-                CqlValue::List(val) => Vec::to_napi_value(
-                    env,
-                    val.into_iter()
-                        .map(|e| CqlValueWrapper::to_napi_value(env, CqlValueWrapper { inner: e }))
-                        .collect(),
-                ),
-                CqlValue::Map(val) => Vec::to_napi_value(
-                    env,
-                    val.into_iter()
-                        .map(|e: (CqlValue, CqlValue)| {
-                            vec![
-                                CqlValueWrapper { inner: e.0 },
-                                CqlValueWrapper { inner: e.1 },
-                            ]
-                        })
-                        .collect(),
-                ),
-                CqlValue::Set(val) => Vec::to_napi_value(
-                    env,
-                    val.into_iter()
-                        .map(|e| CqlValueWrapper::to_napi_value(env, CqlValueWrapper { inner: e }))
-                        .collect(),
-                ),
-                CqlValue::UserDefinedType {
-                    keyspace: _,
-                    name: _,
-                    fields,
+                ColumnType::Collection {
+                    typ: CollectionType::Map(_, _),
+                    ..
                 } => {
-                    // Create an empty JS object
-                    let env_obj = Env::from_raw(env);
-                    let mut obj = Object::new(&env_obj)?;
-
-                    // And fill it with the wrapped values
-                    fields
-                        .into_iter()
-                        .try_for_each(|(field_name, field_value)| {
-                            obj.set_named_property(
-                                &field_name,
-                                field_value.map(|e| {
-                                    CqlValueWrapper::to_napi_value(
-                                        // Value wrapping
-                                        env,
-                                        CqlValueWrapper { inner: e },
-                                    )
-                                }),
+                    let entries: MapIterator<FrameSliceWithMetadata, FrameSliceWithMetadata> =
+                        deserialize(typ, slice)?;
+                    let options = value.options;
+                    // Map is returned as an array of [key, value] arrays
+                    to_js_array(
+                        &env_obj,
+                        entries.map(|entry| {
+                            let (key, value) = entry.map_err(err_to_napi)?;
+                            to_js_array(
+                                &env_obj,
+                                [Ok(options.wrap(key)), Ok(options.wrap(value))].into_iter(),
                             )
-                        })
-                        .map(|_| obj.raw())
-                }
-
-                CqlValue::SmallInt(val) => i16::to_napi_value(env, val),
-                CqlValue::TinyInt(val) => i8::to_napi_value(env, val),
-                CqlValue::Time(val) => {
-                    LocalTimeWrapper::to_napi_value(env, LocalTimeWrapper::from_cql_time(val))
+                        }),
+                    )
                 }
-                CqlValue::Timeuuid(val) => {
-                    Buffer::to_napi_value(env, Buffer::from(val.as_bytes().as_slice()))
+                ColumnType::Vector {
+                    typ: element_type,
+                    dimensions,
+                } => match element_type.as_ref() {
+                    // Fixed-size numeric vectors are decoded directly into typed arrays
+                    ColumnType::Native(NativeType::Float) => Float32Array::to_napi_value(
+                        env,
+                        Float32Array::new(decode_numeric_vector(
+                            slice,
+                            *dimensions,
+                            f32::from_be_bytes,
+                        )?),
+                    ),
+                    ColumnType::Native(NativeType::Double) => Float64Array::to_napi_value(
+                        env,
+                        Float64Array::new(decode_numeric_vector(
+                            slice,
+                            *dimensions,
+                            f64::from_be_bytes,
+                        )?),
+                    ),
+                    ColumnType::Native(NativeType::Int) => Int32Array::to_napi_value(
+                        env,
+                        Int32Array::new(decode_numeric_vector(
+                            slice,
+                            *dimensions,
+                            i32::from_be_bytes,
+                        )?),
+                    ),
+                    _ => {
+                        let elements: VectorIterator<FrameSliceWithMetadata> =
+                            deserialize(typ, slice)?;
+                        to_js_array(
+                            &env_obj,
+                            elements.map(|e| e.map(|e| value.options.wrap(e)).map_err(err_to_napi)),
+                        )
+                    }
+                },
+                ColumnType::UserDefinedType { definition, .. } => {
+                    let fields: UdtIterator = deserialize(typ, slice)?;
+                    let udt_name = format!("{}.{}", definition.keyspace, definition.name);
+                    let mut obj = value.options.create_udt_object(&env_obj, &udt_name)?;
+                    for ((field_name, field_type), field_value) in fields {
+                        obj.set_named_property(
+                            field_name.as_ref(),
+                            CqlValueWrapper {
+                                typ: field_type,
+                                slice: field_value.map_err(err_to_napi)?.flatten(),
+                                options: value.options,
+                            },
+                        )?;
+                    }
+                    // Identity of the type is kept as a non-enumerable property,
+                    // so it's not considered as one of the fields.
+                    // Classes of the registered constructors may define the property on their own.
+                    if !obj.has_named_property("udtName")? {
+                        obj.define_properties(&[Property::new()
+                            .with_utf8_name("udtName")?
+                            .with_value(&env_obj.create_string(&udt_name)?)
+                            .with_property_attributes(PropertyAttributes::Default)])?;
+                    }
+                    Ok(obj.raw())
                 }
-
-                CqlValue::Tuple(val) => Vec::to_napi_value(
-                    env,
-                    val.into_iter()
-                        .map(|v| {
-                            v.map(|e| {
-                                CqlValueWrapper::to_napi_value(
-                                    env,
-                                    CqlValueWrapper { inner: e.clone() },
-                                )
+                ColumnType::Tuple(element_types) => {
+                    let mut slice = slice;
+                    to_js_array(
+                        &env_obj,
+                        element_types.iter().map(|typ| {
+                            // If there are no bytes left to read, the element is considered as null.
+                            // This handles tuples with fewer elements than expected.
+                            let slice = if slice.is_empty() {
+                                None
+                            } else {
+                                slice.read_cql_bytes().map_err(err_to_napi)?
+                            };
+                            Ok(CqlValueWrapper {
+                                typ,
+                                slice,
+                                options: value.options,
                             })
-                        })
-                        .collect(),
-                ),
-
-                CqlValue::Uuid(val) => {
-                    Buffer::to_napi_value(env, Buffer::from(val.as_bytes().as_slice()))
-                }
-
-                CqlValue::Varint(val) => {
-                    let (sign, words) =
-                        num_bigint::BigInt::from_signed_bytes_be(val.as_signed_bytes_be_slice())
-                            .to_u64_digits();
-
-                    BigInt::to_napi_value(
-                        env,
-                        BigInt {
-                            sign_bit: sign == num_bigint::Sign::Minus,
-                            words,
-                        },
+                        }),
                     )
                 }
-                CqlValue::Vector(val) => Vec::to_napi_value(
-                    env,
-                    val.into_iter()
-                        .map(|v| CqlValueWrapper::to_napi_value(env, CqlValueWrapper { inner: v }))
-                        .collect(),
-                ),
-                other => unimplemented!("Missing implementation for CQL value {:?}", other),
+                other => Err(js_typed_error(
+                    format!("Missing implementation for CQL type {other:?}"),
+                    ErrorType::NotSupportedError,
+                )),
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scylla::frame::response::result::TableSpec;
+
+    #[test]
+    fn is_applied_column_checks_name_and_type() {
+        let table = || TableSpec::borrowed("ks", "table");
+        let boolean = || ColumnType::Native(NativeType::Boolean);
+        assert!(is_applied_column(&ColumnSpec::borrowed(
+            APPLIED_COLUMN,
+            boolean(),
+            table()
+        )));
+        assert!(!is_applied_column(&ColumnSpec::borrowed(
+            "applied",
+            boolean(),
+            table()
+        )));
+        assert!(!is_applied_column(&ColumnSpec::borrowed(
+            APPLIED_COLUMN,
+            ColumnType::Native(NativeType::Text),
+            table()
+        )));
+    }
+
+    #[test]
+    fn is_applied_reads_value() {
+        let boolean = ColumnType::Native(NativeType::Boolean);
+        let value = |bytes| Some(FrameSlice::new_borrowed(bytes));
+        assert!(is_applied(&boolean, value(&[1])).unwrap());
+        assert!(!is_applied(&boolean, value(&[0])).unwrap());
+        assert!(is_applied(&boolean, None).unwrap());
+        assert!(is_applied(&boolean, value(&[1, 0])).is_err());
+    }
+}
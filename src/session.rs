@@ -1,15 +1,36 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
 use openssl::ssl::{SslContextBuilder, SslMethod, SslVerifyMode};
-use scylla::client::SelfIdentity;
 use scylla::client::caching_session::CachingSession;
+use scylla::client::session::{Session, SessionConfig};
 use scylla::client::session_builder::SessionBuilder;
+use scylla::client::{Compression, SelfIdentity};
+use scylla::policies::load_balancing::{NodeIdentifier, SingleTargetLoadBalancingPolicy};
+use scylla::policies::reconnect::ExponentialReconnectPolicy;
+use scylla::policies::timestamp_generator::MonotonicTimestampGenerator;
 use scylla::response::PagingState;
-use scylla::statement::batch::Batch;
+use scylla::statement::batch::{Batch, BatchStatement};
+use scylla::statement::prepared::PreparedStatement;
 use scylla::statement::{Consistency, SerialConsistency, Statement};
+use tokio::sync::{Mutex, OnceCell, OwnedRwLockReadGuard, RwLock, RwLockReadGuard, watch};
+use uuid::Uuid;
 
-use crate::errors::{err_to_napi, js_error};
+use crate::concurrent::{ConcurrencyOptions, ConcurrentExecutor};
+use crate::errors::{ErrorType, err_to_napi, js_error, js_typed_error};
 use crate::options;
 use crate::paging::{PagingResult, PagingStateWrapper};
+use crate::policies::address_translation::{JsAddressTranslator, StaticAddressTranslator};
+use crate::policies::lwt_routing::LwtRoutingPolicy;
+use crate::policies::reconnection::{
+    ObservedReconnectPolicy, ReconnectionCounters, ReconnectionPolicyOptions,
+};
+use crate::pooling::PoolingOptions;
+use crate::requests::cancellation::{CancellationHandle, with_cancellation};
+use crate::requests::execution_history::ExecutionHistory;
 use crate::requests::request::{QueryOptionsObj, QueryOptionsWrapper};
+use crate::socket::SocketOptions;
 use crate::types::encoded_data::EncodedValuesWrapper;
 use crate::types::type_wrappers::ComplexType;
 use crate::utils::bigint_to_i64;
@@ -17,6 +38,10 @@ use crate::utils::from_napi_obj::define_js_to_rust_convertible_object;
 use crate::{requests::request::PreparedStatementWrapper, result::QueryResultWrapper};
 
 const DEFAULT_CACHE_SIZE: u32 = 512;
+const DEFAULT_TIMESTAMP_WARNING_THRESHOLD_MS: u32 = 1000;
+const DEFAULT_TIMESTAMP_MIN_LOG_INTERVAL_MS: u32 = 1000;
+// Maximum number of the keyspace sessions kept open at the same time.
+const MAX_KEYSPACE_SESSIONS: usize = 8;
 
 // For now, ssl options include only rejectUnauthorized.
 // In practice, user can provide more options to configure
@@ -27,6 +52,16 @@ define_js_to_rust_convertible_object!(SslOptions {
     reject_unauthorized, rejectUnauthorized: bool
 });
 
+// Configuration of the client side timestamp generator,
+// matching the MonotonicTimestampGenerator from the JS policies.
+// Both values are expressed in milliseconds.
+define_js_to_rust_convertible_object!(TimestampGeneratorOptions {
+    warning_threshold,
+    warningThreshold: u32,
+    min_log_interval,
+    minLogInterval: u32
+});
+
 define_js_to_rust_convertible_object!(SessionOptions {
     connect_points, connectPoints: Vec<String>,
     keyspace, keyspace: String,
@@ -35,41 +70,141 @@ define_js_to_rust_convertible_object!(SessionOptions {
     credentials_username, credentialsUsername: String,
     credentials_password, credentialsPassword: String,
     cache_size, cacheSize: u32,
-    ssl_options, sslOptions: SslOptions
+    ssl_options, sslOptions: SslOptions,
+    reconnection_policy, reconnectionPolicy: ReconnectionPolicyOptions,
+    timestamp_generator, timestampGenerator: TimestampGeneratorOptions,
+    address_map, addressMap: HashMap<String, String>,
+    address_translator, addressTranslator: JsAddressTranslator,
+    pooling, pooling: PoolingOptions,
+    socket_options, socketOptions: SocketOptions,
+    compression, compression: String
 });
 
 #[napi]
 pub struct BatchWrapper {
     inner: Batch,
+    target_host_id: Option<Uuid>,
+    target_shard: Option<u32>,
+    keyspace: Option<String>,
 }
 
+/// Session guard held by the requests that outlive a single method call.
+pub(crate) type OwnedSessionGuard = OwnedRwLockReadGuard<Option<CachingSession>, CachingSession>;
+
+/// Session used for the statements executed in a keyspace other than the keyspace of the client.
+/// It's created on the first use, once for all the concurrent requests in this keyspace.
+type KeyspaceSession = Arc<OnceCell<Arc<CachingSession>>>;
+
+/// Keyspace sessions together with their keyspace names, from the least recently used one.
+type KeyspaceSessions = Vec<(String, KeyspaceSession)>;
+
 #[napi]
 pub struct SessionWrapper {
-    pub(crate) inner: CachingSession,
+    inner: Arc<RwLock<Option<CachingSession>>>,
+    keyspace_sessions: Arc<Mutex<KeyspaceSessions>>,
+    config: SessionConfig,
+    cache_size: usize,
+    // Set when closing starts. Requests that outlive a single method call subscribe to it,
+    // so that they stop accepting new work once the session starts closing.
+    closing: watch::Sender<bool>,
+    pub(crate) reconnection_counters: Arc<ReconnectionCounters>,
+    compression: Option<Compression>,
 }
 
 #[napi]
 impl SessionWrapper {
     /// Creates session based on the provided session options.
-    #[napi]
+    #[napi(catch_unwind)]
     pub async fn create_session(options: SessionOptions) -> napi::Result<Self> {
-        let builder = configure_session_builder(&options)?;
+        let reconnection_counters = Arc::new(ReconnectionCounters::default());
+        let cache_size = options.cache_size.unwrap_or(DEFAULT_CACHE_SIZE) as usize;
+        let builder = configure_session_builder(options, Arc::clone(&reconnection_counters))?;
+        let compression = builder.config.compression;
+        let config = builder.config.clone();
         let session = builder.build().await.map_err(err_to_napi)?;
-        let session: CachingSession = CachingSession::from(
-            session,
-            options.cache_size.unwrap_or(DEFAULT_CACHE_SIZE) as usize,
-        );
-        Ok(SessionWrapper { inner: session })
+        let session: CachingSession = CachingSession::from(session, cache_size);
+        Ok(SessionWrapper {
+            inner: Arc::new(RwLock::new(Some(session))),
+            keyspace_sessions: Default::default(),
+            config,
+            cache_size,
+            closing: watch::Sender::new(false),
+            reconnection_counters,
+            compression,
+        })
+    }
+
+    /// Closes the session, tearing down the connection pools and the control connection.
+    ///
+    /// New requests are rejected as soon as closing starts, while the requests
+    /// that are already executing are allowed to complete. If they don't complete within
+    /// the provided timeout (in milliseconds), this method fails with a timeout error,
+    /// and the session is closed in the background once the remaining requests complete.
+    /// Without the timeout, it waits for all the requests to complete.
+    ///
+    /// After the session is closed, all the methods fail with `ShutdownError`.
+    /// Closing an already closed session has no effect.
+    #[napi(catch_unwind)]
+    pub async fn close(&self, timeout_ms: Option<u32>) -> napi::Result<()> {
+        self.closing.send_replace(true);
+        let inner = Arc::clone(&self.inner);
+        let keyspace_sessions = Arc::clone(&self.keyspace_sessions);
+        // Dropping the session closes all of its connections.
+        // This is done in a separate task, so that it's not abandoned when the timeout expires.
+        let teardown = tokio::spawn(async move {
+            inner.write().await.take();
+            // The requests hold the guard of the main session for their whole duration,
+            // so none of the keyspace sessions is used at this point.
+            keyspace_sessions.lock().await.clear();
+        });
+        let Some(timeout_ms) = timeout_ms else {
+            return teardown.await.map_err(err_to_napi);
+        };
+        match tokio::time::timeout(Duration::from_millis(timeout_ms.into()), teardown).await {
+            Ok(result) => result.map_err(err_to_napi),
+            Err(_) => Err(js_typed_error(
+                format!(
+                    "Requests in progress did not complete within {timeout_ms} ms. \
+                    The session will be closed once they complete"
+                ),
+                ErrorType::OperationTimedOutError,
+            )),
+        }
+    }
+
+    /// Returns the name of the wire protocol compression algorithm configured for the session.
+    ///
+    /// The Rust driver negotiates the compression with each node separately, without exposing the result.
+    /// When the configured algorithm is not supported by a node, connections to this node fall back
+    /// to no compression (and the driver logs a warning about it).
+    #[napi(catch_unwind)]
+    pub fn get_configured_compression(&self) -> Option<&'static str> {
+        self.compression.as_ref().map(Compression::as_str)
     }
 
     /// Returns the name of the current keyspace
-    #[napi]
-    pub fn get_keyspace(&self) -> Option<String> {
-        self.inner
+    #[napi(catch_unwind)]
+    pub fn get_keyspace(&self) -> napi::Result<Option<String>> {
+        Ok(self
+            .session()?
             .get_session()
             .get_keyspace()
             .as_deref()
-            .map(ToOwned::to_owned)
+            .map(ToOwned::to_owned))
+    }
+
+    /// Changes the keyspace used by the session, sending `USE <keyspace>` on all connections.
+    ///
+    /// Executing `USE <keyspace>` statement through any of the query methods
+    /// has the same effect, so the keyspace returned by `get_keyspace` is always up to date.
+    /// If `case_sensitive` is set to true, the keyspace name will be put in quotes.
+    #[napi(catch_unwind)]
+    pub async fn use_keyspace(&self, keyspace: String, case_sensitive: bool) -> napi::Result<()> {
+        self.session()?
+            .get_session()
+            .use_keyspace(keyspace_name(&keyspace, case_sensitive), true)
+            .await
+            .map_err(err_to_napi)
     }
 
     /// Executes unprepared statement. This assumes the types will be either guessed or provided by user.
@@ -79,39 +214,69 @@ impl SessionWrapper {
     /// All parameters must be in a type recognizable by ParameterWrapper
     /// -- each value must be tuple of its ComplexType and the value itself.
     /// If the provided types will not be correct, this query will fail.
-    #[napi]
+    #[napi(catch_unwind)]
     pub async fn query_unpaged_encoded(
         &self,
         query: String,
         params: Vec<EncodedValuesWrapper>,
         options: &QueryOptionsWrapper,
+        cancellation: Option<&CancellationHandle>,
     ) -> napi::Result<QueryResultWrapper> {
-        let statement: Statement = apply_statement_options(query.into(), &options.options)?;
-        let query_result = self
-            .inner
-            .get_session()
-            .query_unpaged(statement, params)
-            .await
-            .map_err(err_to_napi)?;
-        QueryResultWrapper::from_query(query_result)
+        with_cancellation(cancellation, async {
+            let mut statement: Statement = apply_statement_options(query.into(), &options.options)?;
+            let session = self.session()?;
+            let keyspace_session = self
+                .keyspace_session(&session, options.options.keyspace.as_deref())
+                .await?;
+            let session = keyspace_session.as_deref().unwrap_or(&session);
+            let history = Arc::new(ExecutionHistory::for_statement(
+                session.get_session(),
+                statement.get_consistency(),
+                statement.get_execution_profile_handle(),
+            ));
+            statement.set_history_listener(history.clone());
+            check_target_host(
+                session,
+                options.options.target_host_id()?,
+                options.options.shard,
+            )?;
+            let query_result = session
+                .get_session()
+                .query_unpaged(statement, params)
+                .await
+                .map_err(err_to_napi)?;
+            QueryResultWrapper::from_query(query_result, &history)
+        })
+        .await
     }
 
     /// Prepares a statement through rust driver for a given session
     /// Return expected types for the prepared statement
-    #[napi]
+    ///
+    /// The statement is prepared in the keyspace requested in the options, if any.
+    #[napi(catch_unwind)]
     pub async fn prepare_statement(
         &self,
         statement: String,
+        options: &QueryOptionsWrapper,
+        cancellation: Option<&CancellationHandle>,
     ) -> napi::Result<Vec<ComplexType<'static>>> {
-        let statement: Statement = statement.into();
-        let w = PreparedStatementWrapper {
-            prepared: self
-                .inner
-                .add_prepared_statement(&statement) // TODO: change for add_prepared_statement_to_owned after it is made public
-                .await
-                .map_err(err_to_napi)?,
-        };
-        Ok(w.get_expected_types())
+        with_cancellation(cancellation, async {
+            let statement: Statement = statement.into();
+            let session = self.session()?;
+            let keyspace_session = self
+                .keyspace_session(&session, options.options.keyspace.as_deref())
+                .await?;
+            let session = keyspace_session.as_deref().unwrap_or(&session);
+            let w = PreparedStatementWrapper {
+                prepared: session
+                    .add_prepared_statement(&statement) // TODO: change for add_prepared_statement_to_owned after it is made public
+                    .await
+                    .map_err(err_to_napi)?,
+            };
+            Ok(w.get_expected_types())
+        })
+        .await
     }
 
     /// Execute a given prepared statement against the database with provided parameters.
@@ -124,37 +289,115 @@ impl SessionWrapper {
     ///
     /// Currently `execute_unpaged` from rust driver is used, so no paging is done
     /// and there is no support for any query options
-    #[napi]
+    #[napi(catch_unwind)]
     pub async fn execute_prepared_unpaged_encoded(
         &self,
         query: String,
         params: Vec<EncodedValuesWrapper>,
         options: &QueryOptionsWrapper,
+        cancellation: Option<&CancellationHandle>,
     ) -> napi::Result<QueryResultWrapper> {
-        let query = apply_statement_options(query.into(), &options.options)?;
-        QueryResultWrapper::from_query(
-            self.inner
-                .execute_unpaged(query, params)
+        with_cancellation(cancellation, async {
+            let mut statement = apply_statement_options(query.into(), &options.options)?;
+            let session = self.session()?;
+            let keyspace_session = self
+                .keyspace_session(&session, options.options.keyspace.as_deref())
+                .await?;
+            let session = keyspace_session.as_deref().unwrap_or(&session);
+            let history = Arc::new(ExecutionHistory::for_statement(
+                session.get_session(),
+                statement.get_consistency(),
+                statement.get_execution_profile_handle(),
+            ));
+            statement.set_history_listener(history.clone());
+            check_target_host(
+                session,
+                options.options.target_host_id()?,
+                options.options.shard,
+            )?;
+            let query_result = session
+                .execute_unpaged(statement, params)
                 .await
-                .map_err(err_to_napi)?,
-        )
+                .map_err(err_to_napi)?;
+            QueryResultWrapper::from_query(query_result, &history)
+        })
+        .await
     }
 
     /// Executes all statements in the provided batch. Those statements can be either prepared or unprepared.
     ///
     /// Returns a wrapper of the result provided by the rust driver
-    #[napi]
+    #[napi(catch_unwind)]
     pub async fn batch_encoded(
         &self,
         batch: &BatchWrapper,
         params: Vec<Vec<EncodedValuesWrapper>>,
+        cancellation: Option<&CancellationHandle>,
     ) -> napi::Result<QueryResultWrapper> {
-        QueryResultWrapper::from_query(
-            self.inner
-                .batch(&batch.inner, params)
+        with_cancellation(cancellation, async {
+            let session = self.session()?;
+            let keyspace_session = self
+                .keyspace_session(&session, batch.keyspace.as_deref())
+                .await?;
+            let session = keyspace_session.as_deref().unwrap_or(&session);
+            check_target_host(session, batch.target_host_id, batch.target_shard)?;
+            // The caching session prepares all the statements of the batch anyway,
+            // which is needed to check if any of them is a conditional update.
+            let mut statement = session
+                .prepare_batch(&batch.inner)
                 .await
-                .map_err(err_to_napi)?,
+                .map_err(err_to_napi)?;
+            route_conditional_batch_as_lwt(&mut statement, session);
+            let history = Arc::new(ExecutionHistory::for_statement(
+                session.get_session(),
+                statement.get_consistency(),
+                statement.get_execution_profile_handle(),
+            ));
+            statement.set_history_listener(history.clone());
+            QueryResultWrapper::from_query(
+                session
+                    .batch(&statement, params)
+                    .await
+                    .map_err(err_to_napi)?,
+                &history,
+            )
+        })
+        .await
+    }
+
+    /// Creates an executor of the statement, that executes the rows provided in chunks
+    /// once each, with at most `concurrency_level` executions in progress at any time.
+    ///
+    /// Execution errors do not fail the whole execution, and are returned together with the row index.
+    /// When `raise_on_first_error` is set, no new executions are started after the first error.
+    #[napi(catch_unwind)]
+    pub async fn create_concurrent_executor(
+        &self,
+        query: String,
+        options: &QueryOptionsWrapper,
+        concurrency_level: u32,
+        raise_on_first_error: bool,
+        collect_results: bool,
+    ) -> napi::Result<ConcurrentExecutor> {
+        let concurrency =
+            ConcurrencyOptions::new(concurrency_level, raise_on_first_error, collect_results)?;
+        let session = self.owned_session()?;
+        let keyspace_session = self
+            .keyspace_session(&session, options.options.keyspace.as_deref())
+            .await?;
+        let prepared = prepare_for_concurrent_execution(
+            keyspace_session.as_deref().unwrap_or(&session),
+            query,
+            options,
         )
+        .await?;
+        Ok(ConcurrentExecutor::start(
+            session,
+            keyspace_session,
+            prepared,
+            concurrency,
+            self.closing.subscribe(),
+        ))
     }
 
     /// Query a single page of a prepared statement
@@ -162,30 +405,49 @@ impl SessionWrapper {
     /// For the first page, paging state is not required.
     /// For the following pages you need to provide page state
     /// received from the previous page
-    #[napi]
+    #[napi(catch_unwind)]
     pub async fn query_single_page_encoded(
         &self,
         query: String,
         params: Vec<EncodedValuesWrapper>,
         options: &QueryOptionsWrapper,
         paging_state: Option<&PagingStateWrapper>,
+        cancellation: Option<&CancellationHandle>,
     ) -> napi::Result<PagingResult> {
-        let statement: Statement = apply_statement_options(query.into(), &options.options)?;
-        let paging_state = paging_state
-            .map(|e| e.inner.clone())
-            .unwrap_or(PagingState::start());
-
-        let (result, paging_state_response) = self
-            .inner
-            .get_session()
-            .query_single_page(statement, params, paging_state)
-            .await
-            .map_err(err_to_napi)?;
+        with_cancellation(cancellation, async {
+            let mut statement: Statement = apply_statement_options(query.into(), &options.options)?;
+            let session = self.session()?;
+            let keyspace_session = self
+                .keyspace_session(&session, options.options.keyspace.as_deref())
+                .await?;
+            let session = keyspace_session.as_deref().unwrap_or(&session);
+            let history = Arc::new(ExecutionHistory::for_statement(
+                session.get_session(),
+                statement.get_consistency(),
+                statement.get_execution_profile_handle(),
+            ));
+            statement.set_history_listener(history.clone());
+            check_target_host(
+                session,
+                options.options.target_host_id()?,
+                options.options.shard,
+            )?;
+            let paging_state = paging_state
+                .map(|e| e.inner.clone())
+                .unwrap_or(PagingState::start());
+
+            let (result, paging_state_response) = session
+                .get_session()
+                .query_single_page(statement, params, paging_state)
+                .await
+                .map_err(err_to_napi)?;
 
-        Ok(PagingResult {
-            result: QueryResultWrapper::from_query(result)?,
-            paging_state: paging_state_response.into(),
+            Ok(PagingResult {
+                result: QueryResultWrapper::from_query(result, &history)?,
+                paging_state: paging_state_response.into(),
+            })
         })
+        .await
     }
 
     /// Execute a single page of a prepared statement
@@ -193,34 +455,233 @@ impl SessionWrapper {
     /// For the first page, paging state is not required.
     /// For the following pages you need to provide page state
     /// received from the previous page
-    #[napi]
+    #[napi(catch_unwind)]
     pub async fn execute_single_page_encoded(
         &self,
         query: String,
         params: Vec<EncodedValuesWrapper>,
         options: &QueryOptionsWrapper,
         paging_state: Option<&PagingStateWrapper>,
+        cancellation: Option<&CancellationHandle>,
     ) -> napi::Result<PagingResult> {
-        let paging_state = paging_state
-            .map(|e| e.inner.clone())
-            .unwrap_or(PagingState::start());
-        let prepared = apply_statement_options(query.into(), &options.options)?;
-
-        let (result, paging_state) = self
-            .inner
-            .execute_single_page(prepared, params, paging_state)
-            .await
-            .map_err(err_to_napi)?;
-        Ok(PagingResult {
-            result: QueryResultWrapper::from_query(result)?,
-            paging_state: paging_state.into(),
+        with_cancellation(cancellation, async {
+            let paging_state = paging_state
+                .map(|e| e.inner.clone())
+                .unwrap_or(PagingState::start());
+            let mut statement = apply_statement_options(query.into(), &options.options)?;
+            let session = self.session()?;
+            let keyspace_session = self
+                .keyspace_session(&session, options.options.keyspace.as_deref())
+                .await?;
+            let session = keyspace_session.as_deref().unwrap_or(&session);
+            let history = Arc::new(ExecutionHistory::for_statement(
+                session.get_session(),
+                statement.get_consistency(),
+                statement.get_execution_profile_handle(),
+            ));
+            statement.set_history_listener(history.clone());
+            check_target_host(
+                session,
+                options.options.target_host_id()?,
+                options.options.shard,
+            )?;
+
+            let (result, paging_state) = session
+                .execute_single_page(statement, params, paging_state)
+                .await
+                .map_err(err_to_napi)?;
+            Ok(PagingResult {
+                result: QueryResultWrapper::from_query(result, &history)?,
+                paging_state: paging_state.into(),
+            })
         })
+        .await
+    }
+}
+
+impl SessionWrapper {
+    /// Returns the session, unless it has been closed or is being closed.
+    ///
+    /// The returned guard should be held for the whole duration of the request,
+    /// as closing the session waits for all the guards to be released.
+    pub(crate) fn session(&self) -> napi::Result<RwLockReadGuard<'_, CachingSession>> {
+        if *self.closing.borrow() {
+            return Err(session_closed());
+        }
+        // Only closing the session acquires the lock for writing
+        let guard = self.inner.try_read().map_err(|_| session_closed())?;
+        RwLockReadGuard::try_map(guard, Option::as_ref).map_err(|_| session_closed())
+    }
+
+    /// Returns the session, in the same way as `session`,
+    /// for the requests that outlive a single method call.
+    pub(crate) fn owned_session(&self) -> napi::Result<OwnedSessionGuard> {
+        if *self.closing.borrow() {
+            return Err(session_closed());
+        }
+        let guard = Arc::clone(&self.inner)
+            .try_read_owned()
+            .map_err(|_| session_closed())?;
+        OwnedRwLockReadGuard::try_map(guard, Option::as_ref).map_err(|_| session_closed())
+    }
+
+    /// Returns the session that executes the statements in the requested keyspace,
+    /// or None if the statements should be executed with the main session.
+    ///
+    /// Protocol V4, used by the Rust driver, has no way to send the keyspace together with the request.
+    /// Instead, statements in a keyspace other than the keyspace of the main session are executed
+    /// with a separate session, with the requested keyspace set on all of its connections.
+    /// The keyspace name is case sensitive, as it would be in the request.
+    ///
+    /// The session is created with the same configuration as the main session on the first use,
+    /// and reused for the following statements in this keyspace. At most `MAX_KEYSPACE_SESSIONS`
+    /// sessions are kept, the least recently used one is closed once the requests using it complete.
+    async fn keyspace_session(
+        &self,
+        session: &CachingSession,
+        keyspace: Option<&str>,
+    ) -> napi::Result<Option<Arc<CachingSession>>> {
+        let Some(keyspace) = keyspace else {
+            return Ok(None);
+        };
+        if session
+            .get_session()
+            .get_keyspace()
+            .is_some_and(|current| current.as_str() == keyspace)
+        {
+            return Ok(None);
+        }
+        let keyspace_session = {
+            let mut keyspace_sessions = self.keyspace_sessions.lock().await;
+            let keyspace_session = match keyspace_sessions
+                .iter()
+                .position(|(name, _)| name == keyspace)
+            {
+                Some(index) => keyspace_sessions.remove(index).1,
+                None => {
+                    if keyspace_sessions.len() >= MAX_KEYSPACE_SESSIONS {
+                        keyspace_sessions.remove(0);
+                    }
+                    Default::default()
+                }
+            };
+            keyspace_sessions.push((keyspace.to_owned(), Arc::clone(&keyspace_session)));
+            keyspace_session
+        };
+        // Connecting doesn't hold the lock, so it doesn't delay the requests in the other keyspaces.
+        // If it fails, the session is created again by the next request in this keyspace.
+        let keyspace_session = keyspace_session
+            .get_or_try_init(|| async {
+                let mut config = self.config.clone();
+                config.used_keyspace = Some(keyspace.to_owned());
+                config.keyspace_case_sensitive = true;
+                let session = Session::connect(config).await.map_err(err_to_napi)?;
+                Ok::<_, napi::Error>(Arc::new(CachingSession::from(session, self.cache_size)))
+            })
+            .await?;
+        Ok(Some(Arc::clone(keyspace_session)))
+    }
+}
+
+/// Returns the name of the keyspace, as used by the server.
+///
+/// Names that are not case sensitive are lowercased, in the same way as unquoted identifiers.
+/// This way, the keyspace of the session can be compared with the keyspace requested for a statement.
+fn keyspace_name(keyspace: &str, case_sensitive: bool) -> String {
+    if case_sensitive {
+        keyspace.to_owned()
+    } else {
+        keyspace.to_ascii_lowercase()
+    }
+}
+
+/// Error of the requests made after the session started closing.
+pub(crate) fn session_closed() -> napi::Error {
+    js_typed_error("The client has been shut down", ErrorType::ShutdownError)
+}
+
+/// Prepares the statement with the options applied, for the concurrent execution.
+async fn prepare_for_concurrent_execution(
+    session: &CachingSession,
+    query: String,
+    options: &QueryOptionsWrapper,
+) -> napi::Result<PreparedStatement> {
+    let statement = apply_statement_options(query.into(), &options.options)?;
+    check_target_host(
+        session,
+        options.options.target_host_id()?,
+        options.options.shard,
+    )?;
+    session
+        .add_prepared_statement(&statement)
+        .await
+        .map_err(err_to_napi)
+}
+
+/// Routes the batch as LWT, when any of its statements is a conditional update.
+///
+/// Batches that target a specific node keep their load balancing policy.
+fn route_conditional_batch_as_lwt(batch: &mut Batch, session: &CachingSession) {
+    let is_conditional = batch.statements.iter().any(|statement| {
+        matches!(statement, BatchStatement::PreparedStatement(prepared) if prepared.is_confirmed_lwt())
+    });
+    if !is_conditional || batch.get_load_balancing_policy().is_some() {
+        return;
+    }
+    let policy = batch
+        .get_execution_profile_handle()
+        .unwrap_or_else(|| session.get_session().get_default_execution_profile_handle())
+        .to_profile()
+        .get_load_balancing_policy()
+        .clone();
+    batch.set_load_balancing_policy(Some(Arc::new(LwtRoutingPolicy::new(policy))));
+}
+
+/// Ensures the node targeted by the statement is known and reachable,
+/// and that the targeted shard, if any, exists on this node.
+///
+/// Targeted statements do not fall back to other nodes, so without this check
+/// the request would fail with a less descriptive error from the load balancing plan.
+fn check_target_host(
+    session: &CachingSession,
+    host_id: Option<Uuid>,
+    shard: Option<u32>,
+) -> napi::Result<()> {
+    let Some(host_id) = host_id else {
+        return Ok(());
+    };
+    let cluster_state = session.get_session().get_cluster_state();
+    let Some(node) = cluster_state.get_node_by_host_id(host_id) else {
+        return Err(js_typed_error(
+            format!("Target host {host_id} is not part of the cluster"),
+            ErrorType::NoHostAvailableError,
+        ));
+    };
+    if !node.is_connected() {
+        return Err(js_typed_error(
+            format!("Target host {host_id} ({}) is down", node.address),
+            ErrorType::NoHostAvailableError,
+        ));
+    }
+    // Nodes without sharding information (for example Cassandra nodes) have a single shard.
+    let nr_shards = node
+        .sharder()
+        .map_or(1, |sharder| u32::from(sharder.nr_shards.get()));
+    match shard {
+        Some(shard) if shard >= nr_shards => Err(js_typed_error(
+            format!(
+                "Target host {host_id} ({}) has {nr_shards} shards, shard {shard} does not exist",
+                node.address
+            ),
+            ErrorType::ArgumentError,
+        )),
+        _ => Ok(()),
     }
 }
 
 /// Creates object representing a prepared batch of statements.
 /// Requires each passed statement to be already prepared.
-#[napi]
+#[napi(catch_unwind)]
 pub fn create_prepared_batch(
     statements: Vec<String>,
     options: &QueryOptionsWrapper,
@@ -230,15 +691,23 @@ pub fn create_prepared_batch(
         .iter()
         .for_each(|q| batch.append_statement(q.as_str()));
     batch = apply_batch_options(batch, &options.options)?;
-    Ok(BatchWrapper { inner: batch })
+    Ok(BatchWrapper {
+        inner: batch,
+        target_host_id: options.options.target_host_id()?,
+        target_shard: options.options.shard,
+        keyspace: options.options.keyspace.clone(),
+    })
 }
 
-fn configure_session_builder(options: &SessionOptions) -> napi::Result<SessionBuilder> {
+fn configure_session_builder(
+    options: SessionOptions,
+    reconnection_counters: Arc<ReconnectionCounters>,
+) -> napi::Result<SessionBuilder> {
     let mut builder = SessionBuilder::new();
-    builder = builder.custom_identity(self_identity(options));
+    builder = builder.custom_identity(self_identity(&options));
     builder = builder.known_nodes(options.connect_points.as_deref().unwrap_or(&[]));
     if let Some(keyspace) = &options.keyspace {
-        builder = builder.use_keyspace(keyspace, false);
+        builder = builder.use_keyspace(keyspace_name(keyspace, false), true);
     }
     match (
         options.credentials_username.as_ref(),
@@ -248,10 +717,12 @@ fn configure_session_builder(options: &SessionOptions) -> napi::Result<SessionBu
             builder = builder.user(username, password);
         }
         (None, None) => (),
+        // There is a check in JS Client constructor that should have prevented only one credential passed
         (Some(_), None) | (None, Some(_)) => {
-            unreachable!(
-                "There is a check in JS Client constructor that should have prevented only one credential passed"
-            )
+            return Err(js_typed_error(
+                "Both username and password must be provided",
+                ErrorType::DriverInternalError,
+            ));
         }
     }
 
@@ -266,11 +737,88 @@ fn configure_session_builder(options: &SessionOptions) -> napi::Result<SessionBu
 
         builder = builder.tls_context(Some(ssl_context_builder.build()));
     }
+
+    // The policy is always wrapped, so that the reconnection attempts are counted
+    // even when the default policy of the Rust driver is used.
+    let reconnect_policy = match &options.reconnection_policy {
+        Some(policy_options) => policy_options.to_reconnect_policy()?,
+        None => Arc::new(ExponentialReconnectPolicy::new()),
+    };
+    // Session builder has no method for the unstable reconnect policy, so it's set in the config directly.
+    builder.config.reconnect_policy = Arc::new(ObservedReconnectPolicy::new(
+        reconnect_policy,
+        reconnection_counters,
+    ));
+
+    // When no generator is provided, client side timestamps are disabled
+    // and the timestamps are assigned by the server (unless provided explicitly with the query).
+    if let Some(generator_options) = &options.timestamp_generator {
+        builder =
+            builder.timestamp_generator(Arc::new(monotonic_timestamp_generator(generator_options)));
+    }
+
+    match (options.address_map, options.address_translator) {
+        (Some(address_map), None) => {
+            builder =
+                builder.address_translator(Arc::new(StaticAddressTranslator::new(&address_map)?));
+        }
+        (None, Some(translator)) => {
+            builder = builder.address_translator(Arc::new(translator));
+        }
+        (None, None) => (),
+        (Some(_), Some(_)) => {
+            return Err(js_typed_error(
+                "Address map and address translator cannot be provided at the same time",
+                ErrorType::ArgumentError,
+            ));
+        }
+    }
+
+    if let Some(pooling) = &options.pooling {
+        builder = pooling.apply(builder)?;
+    }
+    if let Some(socket_options) = &options.socket_options {
+        builder = socket_options.apply(builder)?;
+    }
+    if let Some(compression) = &options.compression {
+        builder = builder.compression(Some(compression.parse().map_err(|_| {
+            js_typed_error(
+                format!("Unsupported compression: {compression}, expected lz4 or snappy"),
+                ErrorType::ArgumentError,
+            )
+        })?));
+    }
     Ok(builder)
 }
 
+/// Creates a monotonic timestamp generator, that warns about the clock drift as configured in the options.
+///
+/// Drift warnings are issued when the generated timestamps drift into the future
+/// more than the warning threshold, no more than once per log interval.
+/// Log interval equal to 0 disables the warnings.
+fn monotonic_timestamp_generator(
+    options: &TimestampGeneratorOptions,
+) -> MonotonicTimestampGenerator {
+    let generator = MonotonicTimestampGenerator::new();
+    match options
+        .min_log_interval
+        .unwrap_or(DEFAULT_TIMESTAMP_MIN_LOG_INTERVAL_MS)
+    {
+        0 => generator.without_warnings(),
+        interval => generator.with_warning_times(
+            Duration::from_millis(
+                options
+                    .warning_threshold
+                    .unwrap_or(DEFAULT_TIMESTAMP_WARNING_THRESHOLD_MS)
+                    .into(),
+            ),
+            Duration::from_millis(interval.into()),
+        ),
+    }
+}
+
 /// Creates object representing unprepared batch of statements.
-#[napi]
+#[napi(catch_unwind)]
 pub fn create_unprepared_batch(
     statements: Vec<String>,
     options: &QueryOptionsWrapper,
@@ -281,7 +829,12 @@ pub fn create_unprepared_batch(
         .for_each(|q| batch.append_statement(q.as_str()));
 
     batch = apply_batch_options(batch, &options.options)?;
-    Ok(BatchWrapper { inner: batch })
+    Ok(BatchWrapper {
+        inner: batch,
+        target_host_id: options.options.target_host_id()?,
+        target_shard: options.options.shard,
+        keyspace: options.options.keyspace.clone(),
+    })
 }
 
 /// Macro to allow applying options to any query type
@@ -321,6 +874,13 @@ macro_rules! make_apply_options {
                 statement.set_tracing(o);
             }
 
+            if let Some(host_id) = options.target_host_id()? {
+                statement.set_load_balancing_policy(Some(SingleTargetLoadBalancingPolicy::new(
+                    NodeIdentifier::HostId(host_id),
+                    options.shard,
+                )));
+            }
+
             Ok(statement)
         }
     };
@@ -364,3 +924,46 @@ fn self_identity(options: &SessionOptions) -> SelfIdentity<'static> {
     }
     self_identity
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scylla::policies::timestamp_generator::TimestampGenerator;
+
+    fn generator_options(
+        warning_threshold: Option<u32>,
+        min_log_interval: Option<u32>,
+    ) -> TimestampGeneratorOptions {
+        TimestampGeneratorOptions {
+            warning_threshold,
+            min_log_interval,
+        }
+    }
+
+    #[test]
+    fn monotonic_timestamp_generator_uses_options() {
+        // Warning settings of the generator are private, they can be checked only in the debug output
+        let config = |options| format!("{:?}", monotonic_timestamp_generator(&options));
+        assert!(
+            config(generator_options(Some(2000), Some(500)))
+                .contains("warning_threshold: 2s, warning_interval: 500ms")
+        );
+        assert!(
+            config(generator_options(None, None))
+                .contains("warning_threshold: 1s, warning_interval: 1s")
+        );
+        assert!(config(generator_options(Some(2000), Some(0))).contains("config: None"));
+    }
+
+    #[test]
+    fn monotonic_timestamp_generator_is_monotonic() {
+        let generator = monotonic_timestamp_generator(&generator_options(None, Some(0)));
+        let mut last = generator.next_timestamp();
+        // Many timestamps are generated within the same microsecond
+        for _ in 0..10_000 {
+            let next = generator.next_timestamp();
+            assert!(next > last);
+            last = next;
+        }
+    }
+}
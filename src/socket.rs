@@ -0,0 +1,64 @@
+use std::time::Duration;
+
+use scylla::client::session_builder::SessionBuilder;
+
+use crate::errors::{ErrorType, js_typed_error};
+use crate::utils::from_napi_obj::define_js_to_rust_convertible_object;
+
+// Idle time of the connection before the keep-alive probes are sent, when no delay is provided.
+const DEFAULT_KEEP_ALIVE_DELAY: Duration = Duration::from_secs(2 * 60 * 60);
+
+// Times are expressed in milliseconds.
+// They are passed as numbers, so that invalid values can be reported with typed errors.
+#[rustfmt::skip]
+define_js_to_rust_convertible_object!(SocketOptions {
+    connect_timeout, connectTimeout: f64,
+    keep_alive, keepAlive: bool,
+    keep_alive_delay, keepAliveDelay: f64,
+    tcp_no_delay, tcpNoDelay: bool
+});
+
+impl SocketOptions {
+    /// Applies the socket options to the session builder.
+    pub(crate) fn apply(&self, mut builder: SessionBuilder) -> napi::Result<SessionBuilder> {
+        if let Some(timeout) = self.connect_timeout {
+            let timeout = millis_to_duration(timeout, "socketOptions.connectTimeout")?;
+            if timeout.is_zero() {
+                return Err(js_typed_error(
+                    "socketOptions.connectTimeout must be positive",
+                    ErrorType::ArgumentError,
+                ));
+            }
+            builder = builder.connection_timeout(timeout);
+        }
+        if let Some(no_delay) = self.tcp_no_delay {
+            builder = builder.tcp_nodelay(no_delay);
+        }
+        let keep_alive_delay = self
+            .keep_alive_delay
+            .map(|delay| millis_to_duration(delay, "socketOptions.keepAliveDelay"))
+            .transpose()?;
+        // Same as in the JS driver, keep-alive is enabled unless disabled explicitly.
+        // The Rust driver has no way to enable TCP keep-alive with the system default delay,
+        // so the delay of 0 is replaced with the default delay of Linux.
+        builder.config.tcp_keepalive_interval = match self.keep_alive {
+            Some(false) => None,
+            _ => Some(
+                keep_alive_delay
+                    .filter(|delay| !delay.is_zero())
+                    .unwrap_or(DEFAULT_KEEP_ALIVE_DELAY),
+            ),
+        };
+        Ok(builder)
+    }
+}
+
+fn millis_to_duration(millis: f64, name: &str) -> napi::Result<Duration> {
+    if !millis.is_finite() || millis < 0.0 || millis.fract() != 0.0 || millis > u32::MAX as f64 {
+        return Err(js_typed_error(
+            format!("{name} must be a non-negative integer number of milliseconds, got {millis}"),
+            ErrorType::ArgumentError,
+        ));
+    }
+    Ok(Duration::from_millis(millis as u64))
+}
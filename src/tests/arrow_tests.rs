@@ -0,0 +1,176 @@
+use std::sync::Arc;
+
+use arrow_array::{
+    Array, ArrayRef, Int32Array, ListArray, MapArray, StringArray, StructArray,
+    builder::make_builder,
+};
+use scylla::{
+    deserialize::FrameSlice,
+    frame::response::result::{CollectionType, ColumnType, NativeType, UserDefinedType},
+    serialize::{value::SerializeValue, writers::CellWriter},
+    value::CqlValue,
+};
+
+use crate::{
+    arrow::{append_value, arrow_type},
+    errors::err_to_napi,
+};
+
+/// Builds the Arrow array of the given type in the same way as the columns of the record batch,
+/// from the values serialized in the same way as in the response frame
+fn to_array(typ: &ColumnType, values: Vec<Option<CqlValue>>) -> napi::Result<ArrayRef> {
+    let mut builder = make_builder(&arrow_type(typ)?, values.len());
+    for value in values {
+        let bytes = value
+            .map(|value| {
+                let mut bytes = vec![];
+                value
+                    .serialize(typ, CellWriter::new_without_size(&mut bytes))
+                    .map_err(err_to_napi)?;
+                Ok::<_, napi::Error>(bytes)
+            })
+            .transpose()?;
+        append_value(
+            builder.as_mut(),
+            typ,
+            bytes.as_deref().map(FrameSlice::new_borrowed),
+        )?;
+    }
+    Ok(builder.finish())
+}
+
+#[napi(catch_unwind)]
+/// Test function checking the Arrow array built from list<int> values.
+/// Fails with an error when the array doesn't match the expected one.
+pub fn tests_arrow_list() -> napi::Result<()> {
+    let typ = ColumnType::Collection {
+        frozen: false,
+        typ: CollectionType::List(Box::new(ColumnType::Native(NativeType::Int))),
+    };
+    let array = to_array(
+        &typ,
+        vec![
+            Some(CqlValue::List(vec![CqlValue::Int(1), CqlValue::Int(2)])),
+            None,
+            Some(CqlValue::List(vec![CqlValue::Int(3)])),
+        ],
+    )?;
+    let list = array.as_any().downcast_ref::<ListArray>().unwrap();
+    assert_eq!(list.value_offsets(), &[0, 2, 2, 3]);
+    assert!(list.is_null(1));
+    assert_eq!(
+        list.values().as_any().downcast_ref::<Int32Array>().unwrap(),
+        &Int32Array::from(vec![1, 2, 3])
+    );
+    Ok(())
+}
+
+#[napi(catch_unwind)]
+/// Test function checking the Arrow array built from map<text, int> values.
+/// Fails with an error when the array doesn't match the expected one.
+pub fn tests_arrow_map() -> napi::Result<()> {
+    let typ = ColumnType::Collection {
+        frozen: false,
+        typ: CollectionType::Map(
+            Box::new(ColumnType::Native(NativeType::Text)),
+            Box::new(ColumnType::Native(NativeType::Int)),
+        ),
+    };
+    let array = to_array(
+        &typ,
+        vec![
+            Some(CqlValue::Map(vec![
+                (CqlValue::Text("a".to_owned()), CqlValue::Int(1)),
+                (CqlValue::Text("b".to_owned()), CqlValue::Int(2)),
+            ])),
+            None,
+        ],
+    )?;
+    let map = array.as_any().downcast_ref::<MapArray>().unwrap();
+    assert_eq!(map.value_offsets(), &[0, 2, 2]);
+    assert!(map.is_null(1));
+    assert_eq!(
+        map.keys().as_any().downcast_ref::<StringArray>().unwrap(),
+        &StringArray::from(vec!["a", "b"])
+    );
+    assert_eq!(
+        map.values().as_any().downcast_ref::<Int32Array>().unwrap(),
+        &Int32Array::from(vec![1, 2])
+    );
+    Ok(())
+}
+
+#[napi(catch_unwind)]
+/// Test function checking the Arrow array built from user defined type values.
+/// Fails with an error when the array doesn't match the expected one.
+pub fn tests_arrow_udt() -> napi::Result<()> {
+    let typ = ColumnType::UserDefinedType {
+        frozen: true,
+        definition: Arc::new(UserDefinedType {
+            name: "address".into(),
+            keyspace: "ks".into(),
+            field_types: vec![
+                ("street".into(), ColumnType::Native(NativeType::Text)),
+                ("number".into(), ColumnType::Native(NativeType::Int)),
+            ],
+        }),
+    };
+    let array = to_array(
+        &typ,
+        vec![
+            Some(CqlValue::UserDefinedType {
+                keyspace: "ks".to_owned(),
+                name: "address".to_owned(),
+                fields: vec![
+                    ("street".to_owned(), Some(CqlValue::Text("main".to_owned()))),
+                    ("number".to_owned(), None),
+                ],
+            }),
+            None,
+        ],
+    )?;
+    let udt = array.as_any().downcast_ref::<StructArray>().unwrap();
+    assert!(udt.is_valid(0));
+    assert!(udt.is_null(1));
+    assert_eq!(
+        udt.column_by_name("street")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap(),
+        &StringArray::from(vec![Some("main"), None])
+    );
+    assert_eq!(udt.column_by_name("number").unwrap().null_count(), 2);
+    Ok(())
+}
+
+#[napi(catch_unwind)]
+/// Test function checking the Arrow array built from tuple<int, text> values.
+/// Fails with an error when the array doesn't match the expected one.
+pub fn tests_arrow_tuple() -> napi::Result<()> {
+    let typ = ColumnType::Tuple(vec![
+        ColumnType::Native(NativeType::Int),
+        ColumnType::Native(NativeType::Text),
+    ]);
+    let array = to_array(
+        &typ,
+        vec![
+            Some(CqlValue::Tuple(vec![Some(CqlValue::Int(7)), None])),
+            None,
+        ],
+    )?;
+    let tuple = array.as_any().downcast_ref::<StructArray>().unwrap();
+    assert!(tuple.is_valid(0));
+    assert!(tuple.is_null(1));
+    assert_eq!(
+        tuple
+            .column_by_name("0")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap(),
+        &Int32Array::from(vec![Some(7), None])
+    );
+    assert_eq!(tuple.column_by_name("1").unwrap().null_count(), 2);
+    Ok(())
+}
@@ -0,0 +1,7 @@
+use crate::requests::cancellation::{CancellationHandle, with_cancellation};
+
+#[napi(catch_unwind)]
+/// Test function simulating a request that never completes, unless it's cancelled with the handle.
+pub async fn tests_pending_request(cancellation: Option<&CancellationHandle>) -> napi::Result<()> {
+    with_cancellation(cancellation, std::future::pending()).await
+}
@@ -1,10 +1,11 @@
 use crate::errors::{ErrorType, js_typed_error};
 
-#[napi]
+#[napi(catch_unwind)]
 /// Test function that throws specified error with a custom message.
 pub fn throw_test_error(error_type: String, custom_message: Option<String>) -> napi::Result<()> {
     let message = custom_message.unwrap_or_else(|| "Test error".to_string());
     match error_type.as_str() {
+        "AbortError" => Err(js_typed_error(&message, ErrorType::AbortError)),
         "ArgumentError" => Err(js_typed_error(&message, ErrorType::ArgumentError)),
         "AuthenticationError" => Err(js_typed_error(&message, ErrorType::AuthenticationError)),
         "BusyConnectionError" => Err(js_typed_error(&message, ErrorType::BusyConnectionError)),
@@ -13,6 +14,7 @@ pub fn throw_test_error(error_type: String, custom_message: Option<String>) -> n
         "NoHostAvailableError" => Err(js_typed_error(&message, ErrorType::NoHostAvailableError)),
         "NotSupportedError" => Err(js_typed_error(&message, ErrorType::NotSupportedError)),
         "ResponseError" => Err(js_typed_error(&message, ErrorType::ResponseError)),
+        "ShutdownError" => Err(js_typed_error(&message, ErrorType::ShutdownError)),
         "Error" => Err(js_typed_error(&message, ErrorType::Error)),
         "RangeError" => Err(js_typed_error(&message, ErrorType::RangeError)),
         "ReferenceError" => Err(js_typed_error(&message, ErrorType::ReferenceError)),
@@ -21,3 +23,9 @@ pub fn throw_test_error(error_type: String, custom_message: Option<String>) -> n
         _ => Ok(()),
     }
 }
+
+#[napi(catch_unwind)]
+/// Test function that panics, to check that the panic is thrown as an error instead of aborting the process.
+pub fn panic_test_error() {
+    panic!("Test panic");
+}
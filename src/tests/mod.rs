@@ -1,3 +1,7 @@
+pub mod arrow_tests;
+pub mod cancellation_tests;
 pub mod error_throwing_tests;
+pub mod policies_tests;
 pub mod result_tests;
+pub mod socket_tests;
 pub mod utils_tests;
@@ -0,0 +1,21 @@
+use std::time::Duration;
+
+use crate::errors::js_error;
+use crate::policies::address_translation::JsAddressTranslator;
+
+#[napi(catch_unwind)]
+/// Test function translating the "ip:port" address with the JS translator,
+/// that fails when the translator doesn't respond within the timeout.
+pub async fn tests_js_address_translation(
+    translator: JsAddressTranslator,
+    address: String,
+    timeout_ms: u32,
+) -> napi::Result<String> {
+    let address = address.parse().map_err(js_error)?;
+    translator
+        .with_timeout(Duration::from_millis(timeout_ms.into()))
+        .translate(address)
+        .await
+        .map(|translated| translated.to_string())
+        .map_err(js_error)
+}
@@ -5,49 +5,120 @@ use scylla::value::{
 use std::{
     net::{IpAddr, Ipv4Addr},
     str::FromStr,
+    sync::Arc,
 };
 
-use crate::result::CqlValueWrapper;
+use crate::{
+    errors::err_to_napi,
+    result::{CqlValueWrapper, DecodingOptions, TimestampDecoding, create_row_objects},
+};
+use napi::{
+    Env, Unknown,
+    bindgen_prelude::{Array, Function, Object, ToNapiValue},
+};
+use scylla::{
+    deserialize::FrameSlice,
+    frame::response::result::{CollectionType, ColumnType, NativeType, UserDefinedType},
+    serialize::{value::SerializeValue, writers::CellWriter},
+};
 use uuid::uuid;
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with Ascii type
-pub fn tests_get_cql_wrapper_ascii() -> CqlValueWrapper {
+/// Sample CQL value serialized in the same way as in the response frame
+///
+/// When returned from NAPI-RS, it's converted to JS value in the same way
+/// as the values of the query result.
+pub struct SerializedValueWrapper {
+    typ: ColumnType<'static>,
+    bytes: napi::Result<Vec<u8>>,
+}
+
+impl SerializedValueWrapper {
+    fn new(value: CqlValue, typ: ColumnType<'static>) -> Self {
+        let mut bytes = vec![];
+        let result = value
+            .serialize(&typ, CellWriter::new_without_size(&mut bytes))
+            .map(|_| ())
+            .map_err(err_to_napi);
+        SerializedValueWrapper {
+            bytes: result.map(|_| bytes),
+            typ,
+        }
+    }
+
+    /// Converts the value into JS value, in the same way as the values of the query result
+    /// converted with the given options
+    ///
+    /// # Safety
+    ///
+    /// Valid pointer to napi env must be provided
+    unsafe fn convert(
+        self,
+        env: napi::sys::napi_env,
+        options: DecodingOptions,
+    ) -> napi::Result<napi::sys::napi_value> {
+        let bytes = self.bytes?;
+        // Caller of this function ensures a valid pointer to napi env is provided
+        unsafe {
+            CqlValueWrapper::to_napi_value(
+                env,
+                CqlValueWrapper {
+                    typ: &self.typ,
+                    slice: Some(FrameSlice::new_borrowed(&bytes)),
+                    options,
+                },
+            )
+        }
+    }
+}
+
+impl ToNapiValue for SerializedValueWrapper {
+    unsafe fn to_napi_value(
+        env: napi::sys::napi_env,
+        val: Self,
+    ) -> napi::Result<napi::sys::napi_value> {
+        // Caller of this function ensures a valid pointer to napi env is provided
+        unsafe { val.convert(env, DecodingOptions::default()) }
+    }
+}
+
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with Ascii type
+pub fn tests_get_cql_wrapper_ascii() -> SerializedValueWrapper {
     let element = CqlValue::Ascii("test value".to_owned());
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(element, ColumnType::Native(NativeType::Ascii))
 }
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with BigInt type
-pub fn tests_get_cql_wrapper_bigint() -> CqlValueWrapper {
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with BigInt type
+pub fn tests_get_cql_wrapper_bigint() -> SerializedValueWrapper {
     let element = CqlValue::BigInt(69);
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(element, ColumnType::Native(NativeType::BigInt))
 }
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with Boolean type
-pub fn tests_get_cql_wrapper_boolean() -> CqlValueWrapper {
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with Boolean type
+pub fn tests_get_cql_wrapper_boolean() -> SerializedValueWrapper {
     let element = CqlValue::Boolean(false);
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(element, ColumnType::Native(NativeType::Boolean))
 }
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with Blob type
-pub fn tests_get_cql_wrapper_blob() -> CqlValueWrapper {
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with Blob type
+pub fn tests_get_cql_wrapper_blob() -> SerializedValueWrapper {
     let element = CqlValue::Blob((0..4).collect());
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(element, ColumnType::Native(NativeType::Blob))
 }
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with Counter type
-pub fn tests_get_cql_wrapper_counter() -> CqlValueWrapper {
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with Counter type
+pub fn tests_get_cql_wrapper_counter() -> SerializedValueWrapper {
     let element = CqlValue::Counter(Counter(i64::MAX));
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(element, ColumnType::Native(NativeType::Counter))
 }
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with Decimal type
-pub fn tests_get_cql_wrapper_decimal() -> CqlValueWrapper {
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with Decimal type
+pub fn tests_get_cql_wrapper_decimal() -> SerializedValueWrapper {
     let element = CqlValue::Decimal(CqlDecimal::from_signed_be_bytes_slice_and_exponent(
         &[
             1, 53, 169, 169, 173, 175, 83, 216, 15, 110, 137, 47, 175, 202, 192, 196, 222, 179, 11,
@@ -55,85 +126,105 @@ pub fn tests_get_cql_wrapper_decimal() -> CqlValueWrapper {
         ],
         69,
     ));
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(element, ColumnType::Native(NativeType::Decimal))
 }
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with Decimal type with negative sign
-pub fn tests_get_cql_wrapper_decimal_negative() -> CqlValueWrapper {
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with Decimal type with negative sign
+pub fn tests_get_cql_wrapper_decimal_negative() -> SerializedValueWrapper {
     let element = CqlValue::Decimal(CqlDecimal::from_signed_be_bytes_slice_and_exponent(
         &[246],
         0,
     ));
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(element, ColumnType::Native(NativeType::Decimal))
 }
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with Decimal type with negative exponent
-pub fn tests_get_cql_wrapper_decimal_negative_exponent() -> CqlValueWrapper {
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with Decimal type with negative exponent
+pub fn tests_get_cql_wrapper_decimal_negative_exponent() -> SerializedValueWrapper {
     let element = CqlValue::Decimal(CqlDecimal::from_signed_be_bytes_slice_and_exponent(
         &[69],
         -10,
     ));
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(element, ColumnType::Native(NativeType::Decimal))
 }
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with CqlTime type
-pub fn tests_get_cql_wrapper_date() -> CqlValueWrapper {
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with CqlTime type
+pub fn tests_get_cql_wrapper_date() -> SerializedValueWrapper {
     let element = CqlValue::Date(CqlDate((1 << 31) + 7));
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(element, ColumnType::Native(NativeType::Date))
 }
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with Double type
-pub fn tests_get_cql_wrapper_double() -> CqlValueWrapper {
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with Double type
+pub fn tests_get_cql_wrapper_double() -> SerializedValueWrapper {
     let element = CqlValue::Double(f64::MAX);
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(element, ColumnType::Native(NativeType::Double))
 }
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with Duration type
-pub fn tests_get_cql_wrapper_duration() -> CqlValueWrapper {
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with Duration type
+pub fn tests_get_cql_wrapper_duration() -> SerializedValueWrapper {
     let element = CqlValue::Duration(CqlDuration {
         months: 1,
         days: 2,
         nanoseconds: 3,
     });
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(element, ColumnType::Native(NativeType::Duration))
 }
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with Float type
-pub fn tests_get_cql_wrapper_float() -> CqlValueWrapper {
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with Float type
+pub fn tests_get_cql_wrapper_float() -> SerializedValueWrapper {
     let element = CqlValue::Float(0_f32);
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(element, ColumnType::Native(NativeType::Float))
 }
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with Int type
-pub fn tests_get_cql_wrapper_int() -> CqlValueWrapper {
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with Int type
+pub fn tests_get_cql_wrapper_int() -> SerializedValueWrapper {
     let element = CqlValue::Int(i32::MAX);
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(element, ColumnType::Native(NativeType::Int))
 }
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with Text type
-pub fn tests_get_cql_wrapper_text() -> CqlValueWrapper {
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with Text type
+pub fn tests_get_cql_wrapper_text() -> SerializedValueWrapper {
     let element = CqlValue::Text("".to_owned());
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(element, ColumnType::Native(NativeType::Text))
 }
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with Timestamp type
-pub fn tests_get_cql_wrapper_timestamp() -> CqlValueWrapper {
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with Timestamp type
+pub fn tests_get_cql_wrapper_timestamp() -> SerializedValueWrapper {
     let element = CqlValue::Timestamp(CqlTimestamp(1_000_000_i64));
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(element, ColumnType::Native(NativeType::Timestamp))
+}
+
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with Timestamp type,
+/// converted into the provided representation
+pub fn tests_get_cql_wrapper_timestamp_decoded<'env>(
+    env: &'env Env,
+    timestamp_decoding: TimestampDecoding,
+) -> napi::Result<Unknown<'env>> {
+    let element = CqlValue::Timestamp(CqlTimestamp(1_000_000_i64));
+    let value = SerializedValueWrapper::new(element, ColumnType::Native(NativeType::Timestamp));
+    let options = DecodingOptions {
+        timestamp_decoding,
+        ..Default::default()
+    };
+    // The env is provided by NAPI-RS, so it's valid for the duration of this call
+    unsafe {
+        let raw = value.convert(env.raw(), options)?;
+        Ok(Unknown::from_raw_unchecked(env.raw(), raw))
+    }
 }
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with List type
-pub fn tests_get_cql_wrapper_list() -> CqlValueWrapper {
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with List type
+pub fn tests_get_cql_wrapper_list() -> SerializedValueWrapper {
     let element = CqlValue::List(vec![
         CqlValue::Duration(CqlDuration {
             months: 6,
@@ -146,22 +237,34 @@ pub fn tests_get_cql_wrapper_list() -> CqlValueWrapper {
             nanoseconds: 1,
         }),
     ]);
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(
+        element,
+        ColumnType::Collection {
+            frozen: false,
+            typ: CollectionType::List(Box::new(ColumnType::Native(NativeType::Duration))),
+        },
+    )
 }
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with Set type
-pub fn tests_get_cql_wrapper_set() -> CqlValueWrapper {
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with Set type
+pub fn tests_get_cql_wrapper_set() -> SerializedValueWrapper {
     let element = CqlValue::Set(vec![
         CqlValue::Text("some text".to_owned()),
         CqlValue::Text("other text".to_owned()),
     ]);
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(
+        element,
+        ColumnType::Collection {
+            frozen: false,
+            typ: CollectionType::Set(Box::new(ColumnType::Native(NativeType::Text))),
+        },
+    )
 }
 
-/// Test function returning sample CqlValueWrapper with UserDefinedType type.
-#[napi]
-pub fn tests_get_cql_wrapper_udt() -> CqlValueWrapper {
+/// Test function returning sample serialized CQL value with UserDefinedType type.
+#[napi(catch_unwind)]
+pub fn tests_get_cql_wrapper_udt() -> SerializedValueWrapper {
     let element = CqlValue::UserDefinedType {
         keyspace: String::from("keyspace"),
         name: String::from("name"),
@@ -174,87 +277,241 @@ pub fn tests_get_cql_wrapper_udt() -> CqlValueWrapper {
             (String::from("field3"), None),
         ],
     };
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(
+        element,
+        ColumnType::UserDefinedType {
+            frozen: false,
+            definition: Arc::new(UserDefinedType {
+                name: "name".into(),
+                keyspace: "keyspace".into(),
+                field_types: vec![
+                    ("field1".into(), ColumnType::Native(NativeType::Text)),
+                    ("field2".into(), ColumnType::Native(NativeType::Int)),
+                    ("field3".into(), ColumnType::Native(NativeType::Int)),
+                ],
+            }),
+        },
+    )
 }
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with Map type
-pub fn tests_get_cql_wrapper_map() -> CqlValueWrapper {
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with Map type
+pub fn tests_get_cql_wrapper_map() -> SerializedValueWrapper {
     let element = CqlValue::Map(vec![(
         CqlValue::Uuid(uuid!("ffffffff-ffff-ffff-eeee-ffffffffffff")),
         CqlValue::Int(1999),
     )]);
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(
+        element,
+        ColumnType::Collection {
+            frozen: false,
+            typ: CollectionType::Map(
+                Box::new(ColumnType::Native(NativeType::Uuid)),
+                Box::new(ColumnType::Native(NativeType::Int)),
+            ),
+        },
+    )
 }
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with SmallInt type
-pub fn tests_get_cql_wrapper_small_int() -> CqlValueWrapper {
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with SmallInt type
+pub fn tests_get_cql_wrapper_small_int() -> SerializedValueWrapper {
     let element = CqlValue::SmallInt(-1);
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(element, ColumnType::Native(NativeType::SmallInt))
 }
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with TinyInt type
-pub fn tests_get_cql_wrapper_tiny_int() -> CqlValueWrapper {
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with TinyInt type
+pub fn tests_get_cql_wrapper_tiny_int() -> SerializedValueWrapper {
     let element = CqlValue::TinyInt(3);
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(element, ColumnType::Native(NativeType::TinyInt))
 }
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with Tuple type
-pub fn tests_get_cql_wrapper_tuple() -> CqlValueWrapper {
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with Tuple type
+pub fn tests_get_cql_wrapper_tuple() -> SerializedValueWrapper {
     let element = CqlValue::Tuple(vec![
         Some(CqlValue::Text("some text".to_owned())),
         Some(CqlValue::Int(1)),
         None,
     ]);
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(
+        element,
+        ColumnType::Tuple(vec![
+            ColumnType::Native(NativeType::Text),
+            ColumnType::Native(NativeType::Int),
+            ColumnType::Native(NativeType::Int),
+        ]),
+    )
 }
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with Uuid type
-pub fn tests_get_cql_wrapper_uuid() -> CqlValueWrapper {
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with Uuid type
+pub fn tests_get_cql_wrapper_uuid() -> SerializedValueWrapper {
     let element = CqlValue::Uuid(uuid!("ffffffff-ffff-ffff-ffff-ffffffffffff"));
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(element, ColumnType::Native(NativeType::Uuid))
 }
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with Timeuuid type
-pub fn tests_get_cql_wrapper_time_uuid() -> CqlValueWrapper {
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with Timeuuid type
+pub fn tests_get_cql_wrapper_time_uuid() -> SerializedValueWrapper {
     let element =
         CqlValue::Timeuuid(CqlTimeuuid::from_str("8e14e760-7fa8-11eb-bc66-000000000001").unwrap());
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(element, ColumnType::Native(NativeType::Timeuuid))
 }
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with CqlTime type
-pub fn tests_get_cql_wrapper_time() -> CqlValueWrapper {
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with CqlTime type
+pub fn tests_get_cql_wrapper_time() -> SerializedValueWrapper {
     let element = CqlValue::Time(CqlTime(64 * 1_000_000_000));
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(element, ColumnType::Native(NativeType::Time))
 }
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with Inet type
-pub fn tests_get_cql_wrapper_inet() -> CqlValueWrapper {
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with Inet type
+pub fn tests_get_cql_wrapper_inet() -> SerializedValueWrapper {
     let element = CqlValue::Inet(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)));
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(element, ColumnType::Native(NativeType::Inet))
 }
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with Varint type
-pub fn tests_get_cql_wrapper_varint() -> CqlValueWrapper {
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with Varint type
+pub fn tests_get_cql_wrapper_varint() -> SerializedValueWrapper {
     let element = CqlValue::Varint(CqlVarint::from_signed_bytes_be_slice(&[
         1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13,
     ]));
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(element, ColumnType::Native(NativeType::Varint))
 }
 
-#[napi]
-/// Test function returning sample CqlValueWrapper with Varint type
-pub fn tests_get_cql_wrapper_negative_varint() -> CqlValueWrapper {
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with Varint type
+pub fn tests_get_cql_wrapper_negative_varint() -> SerializedValueWrapper {
     let element = CqlValue::Varint(CqlVarint::from_signed_bytes_be_slice(&[
         128, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13,
     ]));
-    CqlValueWrapper { inner: element }
+    SerializedValueWrapper::new(element, ColumnType::Native(NativeType::Varint))
+}
+
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with Vector of floats type
+pub fn tests_get_cql_wrapper_float_vector() -> SerializedValueWrapper {
+    let element = CqlValue::Vector(vec![
+        CqlValue::Float(1.5),
+        CqlValue::Float(-2.25),
+        CqlValue::Float(3.0),
+    ]);
+    SerializedValueWrapper::new(
+        element,
+        ColumnType::Vector {
+            typ: Box::new(ColumnType::Native(NativeType::Float)),
+            dimensions: 3,
+        },
+    )
+}
+
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with Vector of texts type
+pub fn tests_get_cql_wrapper_text_vector() -> SerializedValueWrapper {
+    let element = CqlValue::Vector(vec![
+        CqlValue::Text("some text".to_owned()),
+        CqlValue::Text("other text".to_owned()),
+    ]);
+    SerializedValueWrapper::new(
+        element,
+        ColumnType::Vector {
+            typ: Box::new(ColumnType::Native(NativeType::Text)),
+            dimensions: 2,
+        },
+    )
+}
+
+#[napi(catch_unwind)]
+/// Test function returning sample serialized CQL value with List of UserDefinedType type,
+/// converted with the provided constructors of the user defined types
+pub fn tests_get_cql_wrapper_udt_list<'env>(
+    env: &'env Env,
+    udt_constructors: Option<Object<'env>>,
+) -> napi::Result<Unknown<'env>> {
+    let element = CqlValue::List(vec![CqlValue::UserDefinedType {
+        keyspace: String::from("ks"),
+        name: String::from("address"),
+        fields: vec![
+            (
+                String::from("street"),
+                Some(CqlValue::Text("some street".to_owned())),
+            ),
+            (
+                String::from("id"),
+                Some(CqlValue::Uuid(uuid!(
+                    "ffffffff-ffff-ffff-eeee-ffffffffffff"
+                ))),
+            ),
+        ],
+    }]);
+    let value = SerializedValueWrapper::new(
+        element,
+        ColumnType::Collection {
+            frozen: false,
+            typ: CollectionType::List(Box::new(ColumnType::UserDefinedType {
+                frozen: true,
+                definition: Arc::new(UserDefinedType {
+                    name: "address".into(),
+                    keyspace: "ks".into(),
+                    field_types: vec![
+                        ("street".into(), ColumnType::Native(NativeType::Text)),
+                        ("id".into(), ColumnType::Native(NativeType::Uuid)),
+                    ],
+                }),
+            })),
+        },
+    );
+    // The env is provided by NAPI-RS, so it's valid for the duration of this call
+    unsafe {
+        let options = DecodingOptions {
+            udt_constructors,
+            ..Default::default()
+        };
+        let raw = value.convert(env.raw(), options)?;
+        Ok(Unknown::from_raw_unchecked(env.raw(), raw))
+    }
+}
+
+#[napi(catch_unwind)]
+/// Test function returning sample rows with the columns `id int` and `name text`,
+/// created with the provided row constructor in the same way as the rows of the query result
+pub fn tests_get_rows_as_objects<'env>(
+    env: &'env Env,
+    row_constructor: Function<'env, Array<'env>, Unknown<'env>>,
+) -> napi::Result<Array<'env>> {
+    let types = [
+        ColumnType::Native(NativeType::Int),
+        ColumnType::Native(NativeType::Text),
+    ];
+    let rows = [
+        [CqlValue::Int(1), CqlValue::Text("first".to_owned())],
+        [CqlValue::Int(2), CqlValue::Text("second".to_owned())],
+        [CqlValue::Int(3), CqlValue::Text("third".to_owned())],
+    ]
+    .into_iter()
+    .map(|row| {
+        row.into_iter()
+            .zip(&types)
+            .map(|(value, typ)| SerializedValueWrapper::new(value, typ.clone()).bytes)
+            .collect::<napi::Result<Vec<_>>>()
+    })
+    .collect::<napi::Result<Vec<_>>>()?;
+
+    create_row_objects(
+        env,
+        &["id", "name"],
+        rows.len(),
+        rows.iter().map(|row| {
+            Ok(row
+                .iter()
+                .zip(&types)
+                .map(|(bytes, typ)| Ok((typ, Some(FrameSlice::new_borrowed(bytes))))))
+        }),
+        &row_constructor,
+        DecodingOptions::default(),
+    )
 }
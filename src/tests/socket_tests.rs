@@ -0,0 +1,29 @@
+use napi::{JsValue, bindgen_prelude::JsObjectValue};
+use scylla::client::session_builder::SessionBuilder;
+
+use crate::socket::SocketOptions;
+use crate::utils::to_napi_obj::define_rust_to_js_convertible_object;
+
+// Times are expressed in milliseconds.
+define_rust_to_js_convertible_object!(SocketConfig {
+    connect_timeout,
+    connectTimeout: u32,
+    tcp_no_delay,
+    tcpNoDelay: bool,
+    keep_alive_delay,
+    keepAliveDelay: Option<u32>
+});
+
+#[napi(catch_unwind)]
+/// Test function returning the configuration of the session created with the socket options.
+/// Keep-alive delay is not set, when the keep-alive is disabled.
+pub fn tests_socket_options(options: SocketOptions) -> napi::Result<SocketConfig> {
+    let config = options.apply(SessionBuilder::new())?.config;
+    Ok(SocketConfig {
+        connect_timeout: config.connect_timeout.as_millis() as u32,
+        tcp_no_delay: config.tcp_nodelay,
+        keep_alive_delay: config
+            .tcp_keepalive_interval
+            .map(|delay| delay.as_millis() as u32),
+    })
+}
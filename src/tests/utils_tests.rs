@@ -3,7 +3,7 @@ use napi::bindgen_prelude::BigInt;
 use crate::errors::js_error;
 use crate::utils::bigint_to_i64;
 
-#[napi]
+#[napi(catch_unwind)]
 pub fn tests_bigint_to_i64(value: BigInt, case_id: Option<i32>) -> napi::Result<()> {
     let case_id = match case_id {
         Some(case_id) => case_id,
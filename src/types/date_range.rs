@@ -0,0 +1,650 @@
+use std::sync::LazyLock;
+
+use napi::bindgen_prelude::Buffer;
+use regex::Regex;
+use thiserror::Error;
+
+use crate::errors::{ErrorType, js_typed_error};
+use crate::types::local_date::Ymd;
+
+const MILLIS_IN_SECOND: i64 = 1000;
+const MILLIS_IN_MINUTE: i64 = 60 * MILLIS_IN_SECOND;
+const MILLIS_IN_HOUR: i64 = 60 * MILLIS_IN_MINUTE;
+const MILLIS_IN_DAY: i64 = 24 * MILLIS_IN_HOUR;
+
+// Range of the Date class in JS, in milliseconds since 01.01.1970.
+const MAX_JS_TIMESTAMP: i64 = 8_640_000_000_000_000;
+// Years outside of this range are not representable as Date in JS.
+const MIN_YEAR: i32 = -271_821;
+const MAX_YEAR: i32 = 275_760;
+
+// Same syntax as in the Solr DateRangeField: the date is truncated to the precision of the bound.
+static BOUND_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^([+-]?\d+)(?:-(\d{2})(?:-(\d{2})(?:T(\d{2})(?::(\d{2})(?::(\d{2})(?:\.(\d{1,3}))?)?)?)?)?)?Z?$",
+    )
+    .expect("Invalid regex pattern")
+});
+
+/// Precision of the date range bound.
+///
+/// Values match the ones used in the serialization format of `DateRangeType`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[napi]
+pub enum DateRangePrecision {
+    Year = 0,
+    Month = 1,
+    Day = 2,
+    Hour = 3,
+    Minute = 4,
+    Second = 5,
+    Millisecond = 6,
+}
+
+impl DateRangePrecision {
+    fn from_byte(value: u8) -> Result<Self, DateRangeInvalid> {
+        Ok(match value {
+            0 => DateRangePrecision::Year,
+            1 => DateRangePrecision::Month,
+            2 => DateRangePrecision::Day,
+            3 => DateRangePrecision::Hour,
+            4 => DateRangePrecision::Minute,
+            5 => DateRangePrecision::Second,
+            6 => DateRangePrecision::Millisecond,
+            other => return Err(DateRangeInvalid::Precision(other)),
+        })
+    }
+}
+
+/// Point in time with the precision, used as a bound of the date range.
+/// Unbounded bounds are represented with `None`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Bound {
+    /// Milliseconds since 01.01.1970.
+    timestamp: i64,
+    precision: DateRangePrecision,
+}
+
+/// Fields of the date, from which the bound is formatted and rounded.
+struct DateTimeParts {
+    date: Ymd,
+    hour: i64,
+    minute: i64,
+    second: i64,
+    millisecond: i64,
+}
+
+impl Bound {
+    fn new(timestamp: i64, precision: DateRangePrecision) -> Result<Self, DateRangeInvalid> {
+        if !(-MAX_JS_TIMESTAMP..=MAX_JS_TIMESTAMP).contains(&timestamp) {
+            return Err(DateRangeInvalid::Timestamp(timestamp));
+        }
+        Ok(Bound {
+            timestamp,
+            precision,
+        })
+    }
+
+    fn parse(value: &str) -> Result<Self, DateRangeInvalid> {
+        let invalid = || DateRangeInvalid::Format(value.to_owned());
+        let captures = BOUND_REGEX.captures(value).ok_or_else(invalid)?;
+        // Groups after the year are optional, but each of them requires the previous one.
+        let fields: Vec<&str> = captures
+            .iter()
+            .skip(1)
+            .map_while(|group| group.map(|group| group.as_str()))
+            .collect();
+        let field = |index: usize, default: i64| -> Result<i64, DateRangeInvalid> {
+            fields
+                .get(index)
+                .map_or(Ok(default), |field| field.parse().map_err(|_| invalid()))
+        };
+
+        let year = i32::try_from(field(0, 0)?).map_err(|_| invalid())?;
+        if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
+            return Err(invalid());
+        }
+        let month = field(1, 1)? as i8;
+        let day = field(2, 1)? as i8;
+        let date = Ymd::new(year, month, day).map_err(|_| invalid())?;
+        let (hour, minute, second) = (field(3, 0)?, field(4, 0)?, field(5, 0)?);
+        if hour > 23 || minute > 59 || second > 59 {
+            return Err(invalid());
+        }
+        // Milliseconds are a fraction of the second, so `.5` means 500 milliseconds.
+        let millisecond = match fields.get(6) {
+            Some(fraction) => field(6, 0)? * 10_i64.pow(3 - fraction.len() as u32),
+            None => 0,
+        };
+
+        let precision = DateRangePrecision::from_byte(fields.len() as u8 - 1)?;
+        let parts = DateTimeParts {
+            date,
+            hour,
+            minute,
+            second,
+            millisecond,
+        };
+        Bound::new(parts.to_timestamp(), precision)
+    }
+
+    fn parts(&self) -> Result<DateTimeParts, DateRangeInvalid> {
+        let days = self.timestamp.div_euclid(MILLIS_IN_DAY);
+        let time = self.timestamp.rem_euclid(MILLIS_IN_DAY);
+        Ok(DateTimeParts {
+            date: Ymd::from_days(days).ok_or(DateRangeInvalid::Timestamp(self.timestamp))?,
+            hour: time / MILLIS_IN_HOUR,
+            minute: time % MILLIS_IN_HOUR / MILLIS_IN_MINUTE,
+            second: time % MILLIS_IN_MINUTE / MILLIS_IN_SECOND,
+            millisecond: time % MILLIS_IN_SECOND,
+        })
+    }
+
+    /// Returns the first millisecond of the unit of precision of this bound.
+    fn to_lower(self) -> Result<Self, DateRangeInvalid> {
+        let mut parts = self.parts()?;
+        let precision = self.precision;
+        if precision < DateRangePrecision::Month {
+            parts.date.month = 1;
+        }
+        if precision < DateRangePrecision::Day {
+            parts.date.day = 1;
+        }
+        if precision < DateRangePrecision::Hour {
+            parts.hour = 0;
+        }
+        if precision < DateRangePrecision::Minute {
+            parts.minute = 0;
+        }
+        if precision < DateRangePrecision::Second {
+            parts.second = 0;
+        }
+        if precision < DateRangePrecision::Millisecond {
+            parts.millisecond = 0;
+        }
+        Ok(Bound {
+            // Rounding down the first year of the JS Date range could exceed it.
+            timestamp: parts.to_timestamp().max(-MAX_JS_TIMESTAMP),
+            precision,
+        })
+    }
+
+    /// Returns the last millisecond of the unit of precision of this bound.
+    fn to_upper(self) -> Result<Self, DateRangeInvalid> {
+        let mut parts = self.parts()?;
+        let precision = self.precision;
+        if precision < DateRangePrecision::Month {
+            parts.date.month = 12;
+        }
+        if precision < DateRangePrecision::Day {
+            parts.date.day = Ymd::days_in_month(parts.date.month, parts.date.year);
+        }
+        if precision < DateRangePrecision::Hour {
+            parts.hour = 23;
+        }
+        if precision < DateRangePrecision::Minute {
+            parts.minute = 59;
+        }
+        if precision < DateRangePrecision::Second {
+            parts.second = 59;
+        }
+        if precision < DateRangePrecision::Millisecond {
+            parts.millisecond = 999;
+        }
+        Ok(Bound {
+            // Rounding up the last year of the JS Date range could exceed it.
+            timestamp: parts.to_timestamp().min(MAX_JS_TIMESTAMP),
+            precision,
+        })
+    }
+
+    /// Formats the bound with the fields up to its precision.
+    fn to_format(self) -> Result<String, DateRangeInvalid> {
+        let parts = self.parts()?;
+        let year = parts.date.year;
+        let sign = if year < 0 { "-" } else { "" };
+        let mut value = format!("{sign}{:04}", year.abs());
+        let precision = self.precision;
+        if precision >= DateRangePrecision::Month {
+            value += &format!("-{:02}", parts.date.month);
+        }
+        if precision >= DateRangePrecision::Day {
+            value += &format!("-{:02}", parts.date.day);
+        }
+        if precision >= DateRangePrecision::Hour {
+            value += &format!("T{:02}", parts.hour);
+        }
+        if precision >= DateRangePrecision::Minute {
+            value += &format!(":{:02}", parts.minute);
+        }
+        if precision >= DateRangePrecision::Second {
+            value += &format!(":{:02}", parts.second);
+        }
+        if precision >= DateRangePrecision::Millisecond {
+            value += &format!(".{:03}", parts.millisecond);
+        }
+        // Time of the day is always in UTC.
+        if precision >= DateRangePrecision::Hour {
+            value.push('Z');
+        }
+        Ok(value)
+    }
+
+    fn write_bytes(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&self.timestamp.to_be_bytes());
+        buffer.push(self.precision as u8);
+    }
+
+    fn read_bytes(bytes: &mut &[u8]) -> Result<Self, DateRangeInvalid> {
+        let Some((timestamp, rest)) = bytes.split_first_chunk::<8>() else {
+            return Err(DateRangeInvalid::Buffer);
+        };
+        let Some((precision, rest)) = rest.split_first() else {
+            return Err(DateRangeInvalid::Buffer);
+        };
+        *bytes = rest;
+        Bound::new(
+            i64::from_be_bytes(*timestamp),
+            DateRangePrecision::from_byte(*precision)?,
+        )
+    }
+}
+
+impl DateTimeParts {
+    fn to_timestamp(&self) -> i64 {
+        i64::from(self.date.to_days()) * MILLIS_IN_DAY
+            + self.hour * MILLIS_IN_HOUR
+            + self.minute * MILLIS_IN_MINUTE
+            + self.second * MILLIS_IN_SECOND
+            + self.millisecond
+    }
+}
+
+/// Type of the date range, as the first byte of the `DateRangeType` serialization format.
+#[derive(Clone, Copy)]
+#[repr(u8)]
+enum DateRangeKind {
+    SingleValue = 0,
+    ClosedRange = 1,
+    OpenRangeHigh = 2,
+    OpenRangeLow = 3,
+    BothOpenRange = 4,
+    SingleValueOpen = 5,
+}
+
+/// Date range is either a single value, or a range between two bounds, which includes both of them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum DateRange {
+    Single(Option<Bound>),
+    Range(Option<Bound>, Option<Bound>),
+}
+
+impl DateRange {
+    fn parse(value: &str) -> Result<Self, DateRangeInvalid> {
+        let parse_bound = |bound: &str| match bound {
+            "*" => Ok(None),
+            bound => Bound::parse(bound).map(Some),
+        };
+        match value
+            .strip_prefix('[')
+            .and_then(|range| range.strip_suffix(']'))
+        {
+            Some(range) => {
+                let (lower, upper) = range
+                    .split_once(" TO ")
+                    .ok_or_else(|| DateRangeInvalid::Format(value.to_owned()))?;
+                Ok(DateRange::Range(
+                    parse_bound(lower.trim())?,
+                    parse_bound(upper.trim())?,
+                ))
+            }
+            None => Ok(DateRange::Single(parse_bound(value.trim())?)),
+        }
+    }
+
+    fn to_bytes(self) -> Vec<u8> {
+        let (kind, bounds) = match self {
+            DateRange::Single(Some(value)) => (DateRangeKind::SingleValue, [Some(value), None]),
+            DateRange::Single(None) => (DateRangeKind::SingleValueOpen, [None, None]),
+            DateRange::Range(Some(lower), Some(upper)) => {
+                (DateRangeKind::ClosedRange, [Some(lower), Some(upper)])
+            }
+            DateRange::Range(Some(lower), None) => {
+                (DateRangeKind::OpenRangeHigh, [Some(lower), None])
+            }
+            DateRange::Range(None, Some(upper)) => {
+                (DateRangeKind::OpenRangeLow, [Some(upper), None])
+            }
+            DateRange::Range(None, None) => (DateRangeKind::BothOpenRange, [None, None]),
+        };
+        let mut buffer = vec![kind as u8];
+        bounds
+            .iter()
+            .flatten()
+            .for_each(|bound| bound.write_bytes(&mut buffer));
+        buffer
+    }
+
+    fn to_format(self) -> Result<String, DateRangeInvalid> {
+        let format_bound =
+            |bound: Option<Bound>| bound.map_or(Ok("*".to_owned()), Bound::to_format);
+        match self {
+            DateRange::Single(value) => format_bound(value),
+            DateRange::Range(lower, upper) => Ok(format!(
+                "[{} TO {}]",
+                format_bound(lower)?,
+                format_bound(upper)?
+            )),
+        }
+    }
+
+    fn from_bytes(mut bytes: &[u8]) -> Result<Self, DateRangeInvalid> {
+        let Some((kind, rest)) = bytes.split_first() else {
+            return Err(DateRangeInvalid::Buffer);
+        };
+        bytes = rest;
+        let range = match *kind {
+            0 => DateRange::Single(Some(Bound::read_bytes(&mut bytes)?)),
+            1 => {
+                let lower = Bound::read_bytes(&mut bytes)?;
+                DateRange::Range(Some(lower), Some(Bound::read_bytes(&mut bytes)?))
+            }
+            2 => DateRange::Range(Some(Bound::read_bytes(&mut bytes)?), None),
+            3 => DateRange::Range(None, Some(Bound::read_bytes(&mut bytes)?)),
+            4 => DateRange::Range(None, None),
+            5 => DateRange::Single(None),
+            _ => return Err(DateRangeInvalid::Buffer),
+        };
+        if !bytes.is_empty() {
+            return Err(DateRangeInvalid::Buffer);
+        }
+        Ok(range)
+    }
+}
+
+/// Wrapper for a bound of the date range.
+#[napi]
+pub struct DateRangeBoundWrapper {
+    inner: Bound,
+}
+
+#[napi]
+impl DateRangeBoundWrapper {
+    /// Creates a bound from the number of milliseconds since 01.01.1970 and the precision.
+    #[napi(constructor, catch_unwind)]
+    pub fn new(timestamp: i64, precision: DateRangePrecision) -> napi::Result<Self> {
+        Ok(DateRangeBoundWrapper {
+            inner: Bound::new(timestamp, precision)?,
+        })
+    }
+
+    /// Parses the bound from the string, the precision is based on the number of provided fields.
+    ///
+    /// Unbounded bound (`*`) is not accepted.
+    #[napi(factory, catch_unwind)]
+    pub fn from_string(value: String) -> napi::Result<Self> {
+        Ok(DateRangeBoundWrapper {
+            inner: Bound::parse(&value)?,
+        })
+    }
+
+    #[napi(getter, catch_unwind)]
+    pub fn timestamp(&self) -> i64 {
+        self.inner.timestamp
+    }
+
+    #[napi(getter, catch_unwind)]
+    pub fn precision(&self) -> DateRangePrecision {
+        self.inner.precision
+    }
+
+    /// Returns the bound rounded down to the precision.
+    #[napi(catch_unwind)]
+    pub fn to_lower_bound(&self) -> napi::Result<Self> {
+        Ok(DateRangeBoundWrapper {
+            inner: self.inner.to_lower()?,
+        })
+    }
+
+    /// Returns the bound rounded up to the precision.
+    #[napi(catch_unwind)]
+    pub fn to_upper_bound(&self) -> napi::Result<Self> {
+        Ok(DateRangeBoundWrapper {
+            inner: self.inner.to_upper()?,
+        })
+    }
+
+    #[napi(js_name = "toString", catch_unwind)]
+    pub fn to_format(&self) -> napi::Result<String> {
+        Ok(self.inner.to_format()?)
+    }
+}
+
+/// Wrapper for a value of the `DateRangeType` custom type.
+///
+/// Unbounded bounds are represented with `null`.
+#[napi]
+pub struct DateRangeWrapper {
+    inner: DateRange,
+}
+
+#[napi]
+impl DateRangeWrapper {
+    /// Creates a date range consisting of a single value.
+    #[napi(factory, catch_unwind)]
+    pub fn single(value: Option<&DateRangeBoundWrapper>) -> Self {
+        DateRangeWrapper {
+            inner: DateRange::Single(value.map(|value| value.inner)),
+        }
+    }
+
+    /// Creates a date range between the bounds, including both of them.
+    #[napi(factory, catch_unwind)]
+    pub fn range(
+        lower: Option<&DateRangeBoundWrapper>,
+        upper: Option<&DateRangeBoundWrapper>,
+    ) -> Self {
+        DateRangeWrapper {
+            inner: DateRange::Range(
+                lower.map(|lower| lower.inner),
+                upper.map(|upper| upper.inner),
+            ),
+        }
+    }
+
+    /// Parses the date range from the string, either a single value (`2015-02` or `*`)
+    /// or a range (`[2015-02 TO 2016]`, `[* TO 2016]`).
+    #[napi(factory, catch_unwind)]
+    pub fn from_string(value: String) -> napi::Result<Self> {
+        Ok(DateRangeWrapper {
+            inner: DateRange::parse(&value)?,
+        })
+    }
+
+    /// Deserializes the date range from the bytes of the `DateRangeType` custom type.
+    #[napi(factory, catch_unwind)]
+    pub fn from_buffer(value: Buffer) -> napi::Result<Self> {
+        Ok(DateRangeWrapper {
+            inner: DateRange::from_bytes(&value)?,
+        })
+    }
+
+    /// Serializes the date range into the bytes of the `DateRangeType` custom type.
+    #[napi(catch_unwind)]
+    pub fn to_buffer(&self) -> Buffer {
+        self.inner.to_bytes().into()
+    }
+
+    #[napi(catch_unwind)]
+    pub fn is_range(&self) -> bool {
+        matches!(self.inner, DateRange::Range(..))
+    }
+
+    /// For a single value date range, returns the value.
+    #[napi(catch_unwind)]
+    pub fn get_lower_bound(&self) -> Option<DateRangeBoundWrapper> {
+        match self.inner {
+            DateRange::Single(bound) | DateRange::Range(bound, _) => bound,
+        }
+        .map(|inner| DateRangeBoundWrapper { inner })
+    }
+
+    #[napi(catch_unwind)]
+    pub fn get_upper_bound(&self) -> Option<DateRangeBoundWrapper> {
+        match self.inner {
+            DateRange::Single(_) => None,
+            DateRange::Range(_, bound) => bound,
+        }
+        .map(|inner| DateRangeBoundWrapper { inner })
+    }
+
+    #[napi(js_name = "toString", catch_unwind)]
+    pub fn to_format(&self) -> napi::Result<String> {
+        Ok(self.inner.to_format()?)
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+enum DateRangeInvalid {
+    #[error("Invalid format of the date range: {0}")]
+    Format(String),
+    #[error("Invalid precision of the date range bound: {0}")]
+    Precision(u8),
+    #[error("Timestamp of the date range bound out of the range of Date: {0}")]
+    Timestamp(i64),
+    #[error("Invalid serialized date range")]
+    Buffer,
+}
+
+impl From<DateRangeInvalid> for napi::Error {
+    fn from(value: DateRangeInvalid) -> Self {
+        js_typed_error(value, ErrorType::TypeError)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_format() {
+        for value in [
+            "2015",
+            "-0001",
+            "2015-02",
+            "2012-02-29",
+            "2015-02-10T10Z",
+            "2015-02-10T10:15Z",
+            "2015-02-10T10:15:30Z",
+            "2015-02-10T10:15:30.123Z",
+            "*",
+            "[2015 TO 2016-01-30]",
+            "[* TO 2016-01-30T23:59:59.999Z]",
+            "[1969-12-31T23:59Z TO *]",
+            "[* TO *]",
+        ] {
+            assert_eq!(DateRange::parse(value).unwrap().to_format().unwrap(), value);
+        }
+        assert_eq!(
+            DateRange::parse("2015-02-10T10:15:30.5")
+                .unwrap()
+                .to_format()
+                .unwrap(),
+            "2015-02-10T10:15:30.500Z"
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for value in [
+            "",
+            "2015-2",
+            "2015-13",
+            "2015-02-29",
+            "2015-02-10T24Z",
+            "[2015 TO]",
+            "[2015, 2016]",
+            "2015 TO 2016",
+            "999999",
+        ] {
+            assert!(DateRange::parse(value).is_err(), "{value}");
+        }
+    }
+
+    #[test]
+    fn test_bytes() {
+        for value in [
+            "2015-02-10T10:15:30.123Z",
+            "*",
+            "[2015 TO 2016-01-30]",
+            "[* TO 2016-01]",
+            "[1969-12-31T23:59Z TO *]",
+            "[* TO *]",
+        ] {
+            let range = DateRange::parse(value).unwrap();
+            assert_eq!(DateRange::from_bytes(&range.to_bytes()), Ok(range));
+        }
+        let range = DateRange::parse("[1970 TO 1970-01-01T00:00:00.001Z]").unwrap();
+        assert_eq!(
+            range.to_bytes(),
+            [
+                [1].as_slice(),
+                &[0; 8],
+                &[0],
+                &[0, 0, 0, 0, 0, 0, 0, 1],
+                &[6]
+            ]
+            .concat()
+        );
+        assert_eq!(
+            DateRange::from_bytes(&[0, 1]),
+            Err(DateRangeInvalid::Buffer)
+        );
+        assert_eq!(
+            DateRange::from_bytes(&[4, 0]),
+            Err(DateRangeInvalid::Buffer)
+        );
+    }
+
+    #[test]
+    fn test_rounding() {
+        let bound = Bound::parse("2016-02").unwrap();
+        assert_eq!(bound.to_lower().unwrap().to_format().unwrap(), "2016-02");
+        assert_eq!(
+            Bound {
+                precision: DateRangePrecision::Millisecond,
+                ..bound.to_upper().unwrap()
+            }
+            .to_format()
+            .unwrap(),
+            "2016-02-29T23:59:59.999Z"
+        );
+        let bound = Bound::parse("1969-12-31T23:59:59.999Z").unwrap();
+        let bound = Bound {
+            precision: DateRangePrecision::Day,
+            ..bound
+        };
+        assert_eq!(
+            Bound {
+                precision: DateRangePrecision::Millisecond,
+                ..bound.to_lower().unwrap()
+            }
+            .to_format()
+            .unwrap(),
+            "1969-12-31T00:00:00.000Z"
+        );
+    }
+
+    #[test]
+    fn test_timestamp_out_of_range() {
+        let bound = Bound {
+            timestamp: i64::MAX,
+            precision: DateRangePrecision::Year,
+        };
+        let error = DateRangeInvalid::Timestamp(i64::MAX);
+        assert_eq!(bound.to_format(), Err(error.clone()));
+        assert_eq!(bound.to_lower(), Err(error.clone()));
+        assert_eq!(bound.to_upper(), Err(error.clone()));
+        assert_eq!(DateRange::Range(None, Some(bound)).to_format(), Err(error));
+    }
+}
@@ -12,7 +12,7 @@ pub struct DurationWrapper {
 
 #[napi]
 impl DurationWrapper {
-    #[napi]
+    #[napi(catch_unwind)]
     pub fn new(months: i32, days: i32, ns_bigint: BigInt) -> napi::Result<Self> {
         Ok(DurationWrapper {
             months,
@@ -21,7 +21,7 @@ impl DurationWrapper {
         })
     }
 
-    #[napi]
+    #[napi(catch_unwind)]
     pub fn get_nanoseconds(&self) -> BigInt {
         let tmp: i128 = self.nanoseconds.into();
         let mut res: BigInt = BigInt::from(tmp.abs());
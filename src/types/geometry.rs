@@ -0,0 +1,464 @@
+use std::fmt::{self, Write};
+
+use napi::bindgen_prelude::Buffer;
+use thiserror::Error;
+
+use crate::errors::{ErrorType, js_typed_error};
+
+// Byte order markers of the WKB format.
+const BIG_ENDIAN: u8 = 0;
+const LITTLE_ENDIAN: u8 = 1;
+
+/// Kind of the geometry.
+///
+/// Values match the geometry type codes used in the WKB format.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[napi]
+pub enum GeometryKind {
+    Point = 1,
+    LineString = 2,
+    Polygon = 3,
+}
+
+/// Point in a two-dimensional plane.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+/// Value of one of the `PointType`, `LineStringType` and `PolygonType` custom types.
+#[derive(Clone, Debug, PartialEq)]
+enum Geometry {
+    Point(Point),
+    LineString(Vec<Point>),
+    Polygon(Vec<Vec<Point>>),
+}
+
+impl Point {
+    fn new(x: f64, y: f64) -> Result<Self, GeometryInvalid> {
+        if !x.is_finite() || !y.is_finite() {
+            return Err(GeometryInvalid::Coordinate);
+        }
+        Ok(Point { x, y })
+    }
+
+    /// Creates the points from the flattened list of coordinates: `[x1, y1, x2, y2, ...]`.
+    fn from_coordinates(coordinates: &[f64]) -> Result<Vec<Self>, GeometryInvalid> {
+        if !coordinates.len().is_multiple_of(2) {
+            return Err(GeometryInvalid::Coordinate);
+        }
+        coordinates
+            .chunks_exact(2)
+            .map(|point| Point::new(point[0], point[1]))
+            .collect()
+    }
+
+    fn to_coordinates(points: &[Self]) -> Vec<f64> {
+        points.iter().flat_map(|point| [point.x, point.y]).collect()
+    }
+
+    /// Parses the point from the WKT coordinates: `x y`.
+    fn parse(value: &str) -> Result<Self, GeometryInvalid> {
+        let invalid = || GeometryInvalid::Format(value.to_owned());
+        let mut coordinates = value
+            .split_whitespace()
+            .map(|coordinate| coordinate.parse::<f64>().map_err(|_| invalid()));
+        let (Some(x), Some(y), None) = (coordinates.next(), coordinates.next(), coordinates.next())
+        else {
+            return Err(invalid());
+        };
+        Point::new(x?, y?)
+    }
+
+    /// Parses the list of points from the WKT coordinates: `x1 y1, x2 y2`.
+    fn parse_list(value: &str) -> Result<Vec<Self>, GeometryInvalid> {
+        value.split(',').map(Point::parse).collect()
+    }
+
+    fn write_list(points: &[Self], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char('(')?;
+        for (i, point) in points.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{} {}", point.x, point.y)?;
+        }
+        f.write_char(')')
+    }
+}
+
+impl Geometry {
+    /// Parses the geometry from the WKT format, for example `POINT (1 2)`,
+    /// `LINESTRING (1 2, 3 4)` or `POLYGON ((1 1, 3 1, 2 3, 1 1))`.
+    fn parse(value: &str) -> Result<Self, GeometryInvalid> {
+        let invalid = || GeometryInvalid::Format(value.to_owned());
+        let value = value.trim();
+        let (kind, body) = value.split_at(
+            value
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(value.len()),
+        );
+        let body = body.trim();
+        let kind = match kind.to_ascii_uppercase().as_str() {
+            "POINT" => GeometryKind::Point,
+            "LINESTRING" => GeometryKind::LineString,
+            "POLYGON" => GeometryKind::Polygon,
+            _ => return Err(invalid()),
+        };
+        // The point can't be empty, as it's not possible to represent it in the WKB format.
+        if body.eq_ignore_ascii_case("EMPTY") {
+            return match kind {
+                GeometryKind::Point => Err(invalid()),
+                GeometryKind::LineString => Ok(Geometry::LineString(vec![])),
+                GeometryKind::Polygon => Ok(Geometry::Polygon(vec![])),
+            };
+        }
+        let body = strip_parentheses(body).ok_or_else(invalid)?;
+        Ok(match kind {
+            GeometryKind::Point => Geometry::Point(Point::parse(body)?),
+            GeometryKind::LineString => Geometry::LineString(Point::parse_list(body)?),
+            GeometryKind::Polygon => {
+                let mut rings = vec![];
+                let mut rest = body.trim_start();
+                loop {
+                    let end = rest.find(')').ok_or_else(invalid)?;
+                    let ring = strip_parentheses(&rest[..=end]).ok_or_else(invalid)?;
+                    rings.push(Point::parse_list(ring)?);
+                    rest = rest[end + 1..].trim_start();
+                    match rest.strip_prefix(',') {
+                        Some(next) => rest = next.trim_start(),
+                        None if rest.is_empty() => break,
+                        None => return Err(invalid()),
+                    }
+                }
+                Geometry::Polygon(rings)
+            }
+        })
+    }
+
+    fn kind(&self) -> GeometryKind {
+        match self {
+            Geometry::Point(_) => GeometryKind::Point,
+            Geometry::LineString(_) => GeometryKind::LineString,
+            Geometry::Polygon(_) => GeometryKind::Polygon,
+        }
+    }
+
+    /// Serializes the geometry into the WKB format, using the little-endian byte order.
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut buffer = vec![LITTLE_ENDIAN];
+        buffer.extend((self.kind() as u32).to_le_bytes());
+        let write_points = |buffer: &mut Vec<u8>, points: &[Point]| {
+            buffer.extend((points.len() as u32).to_le_bytes());
+            for point in points {
+                buffer.extend(point.x.to_le_bytes());
+                buffer.extend(point.y.to_le_bytes());
+            }
+        };
+        match self {
+            Geometry::Point(point) => {
+                buffer.extend(point.x.to_le_bytes());
+                buffer.extend(point.y.to_le_bytes());
+            }
+            Geometry::LineString(points) => write_points(&mut buffer, points),
+            Geometry::Polygon(rings) => {
+                buffer.extend((rings.len() as u32).to_le_bytes());
+                for ring in rings {
+                    write_points(&mut buffer, ring);
+                }
+            }
+        }
+        buffer
+    }
+
+    /// Deserializes the geometry from the WKB format, in any of the byte orders.
+    fn from_bytes(bytes: &[u8]) -> Result<Self, GeometryInvalid> {
+        let (byte_order, bytes) = bytes.split_first().ok_or(GeometryInvalid::Buffer)?;
+        let mut reader = WkbReader {
+            bytes,
+            little_endian: match *byte_order {
+                BIG_ENDIAN => false,
+                LITTLE_ENDIAN => true,
+                _ => return Err(GeometryInvalid::Buffer),
+            },
+        };
+        let geometry = match reader.read_u32()? {
+            1 => Geometry::Point(reader.read_point()?),
+            2 => Geometry::LineString(reader.read_points()?),
+            3 => {
+                let count = reader.read_count(4)?;
+                Geometry::Polygon(
+                    (0..count)
+                        .map(|_| reader.read_points())
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+            _ => return Err(GeometryInvalid::Buffer),
+        };
+        if !reader.bytes.is_empty() {
+            return Err(GeometryInvalid::Buffer);
+        }
+        Ok(geometry)
+    }
+}
+
+impl fmt::Display for Geometry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Geometry::Point(point) => write!(f, "POINT ({} {})", point.x, point.y),
+            Geometry::LineString(points) if points.is_empty() => f.write_str("LINESTRING EMPTY"),
+            Geometry::LineString(points) => {
+                f.write_str("LINESTRING ")?;
+                Point::write_list(points, f)
+            }
+            Geometry::Polygon(rings) if rings.is_empty() => f.write_str("POLYGON EMPTY"),
+            Geometry::Polygon(rings) => {
+                f.write_str("POLYGON (")?;
+                for (i, ring) in rings.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    Point::write_list(ring, f)?;
+                }
+                f.write_char(')')
+            }
+        }
+    }
+}
+
+fn strip_parentheses(value: &str) -> Option<&str> {
+    value.strip_prefix('(')?.strip_suffix(')')
+}
+
+struct WkbReader<'a> {
+    bytes: &'a [u8],
+    little_endian: bool,
+}
+
+impl WkbReader<'_> {
+    fn read<const N: usize>(&mut self) -> Result<[u8; N], GeometryInvalid> {
+        let (value, rest) = self
+            .bytes
+            .split_first_chunk::<N>()
+            .ok_or(GeometryInvalid::Buffer)?;
+        self.bytes = rest;
+        Ok(*value)
+    }
+
+    fn read_u32(&mut self) -> Result<u32, GeometryInvalid> {
+        let bytes = self.read()?;
+        Ok(if self.little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn read_f64(&mut self) -> Result<f64, GeometryInvalid> {
+        let bytes = self.read()?;
+        Ok(if self.little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    /// Reads the number of elements, checking that the remaining bytes
+    /// can contain them, so that the invalid count doesn't cause a huge allocation.
+    fn read_count(&mut self, element_size: usize) -> Result<usize, GeometryInvalid> {
+        let count = self.read_u32()? as usize;
+        if count.saturating_mul(element_size) > self.bytes.len() {
+            return Err(GeometryInvalid::Buffer);
+        }
+        Ok(count)
+    }
+
+    fn read_point(&mut self) -> Result<Point, GeometryInvalid> {
+        let x = self.read_f64()?;
+        Point::new(x, self.read_f64()?)
+    }
+
+    fn read_points(&mut self) -> Result<Vec<Point>, GeometryInvalid> {
+        let count = self.read_count(16)?;
+        (0..count).map(|_| self.read_point()).collect()
+    }
+}
+
+/// Wrapper for a value of the `PointType`, `LineStringType` or `PolygonType` custom type.
+///
+/// Points are exchanged with JS as flattened lists of coordinates: `[x1, y1, x2, y2, ...]`.
+#[napi]
+pub struct GeometryWrapper {
+    inner: Geometry,
+}
+
+#[napi]
+impl GeometryWrapper {
+    #[napi(factory, catch_unwind)]
+    pub fn point(x: f64, y: f64) -> napi::Result<Self> {
+        Ok(GeometryWrapper {
+            inner: Geometry::Point(Point::new(x, y)?),
+        })
+    }
+
+    #[napi(factory, catch_unwind)]
+    pub fn line_string(coordinates: Vec<f64>) -> napi::Result<Self> {
+        Ok(GeometryWrapper {
+            inner: Geometry::LineString(Point::from_coordinates(&coordinates)?),
+        })
+    }
+
+    #[napi(factory, catch_unwind)]
+    pub fn polygon(rings: Vec<Vec<f64>>) -> napi::Result<Self> {
+        Ok(GeometryWrapper {
+            inner: Geometry::Polygon(
+                rings
+                    .iter()
+                    .map(|ring| Point::from_coordinates(ring))
+                    .collect::<Result<_, _>>()?,
+            ),
+        })
+    }
+
+    /// Parses the geometry from the WKT format.
+    #[napi(factory, catch_unwind)]
+    pub fn from_string(value: String) -> napi::Result<Self> {
+        Ok(GeometryWrapper {
+            inner: Geometry::parse(&value)?,
+        })
+    }
+
+    /// Deserializes the geometry from the WKB format.
+    #[napi(factory, catch_unwind)]
+    pub fn from_buffer(value: Buffer) -> napi::Result<Self> {
+        Ok(GeometryWrapper {
+            inner: Geometry::from_bytes(&value)?,
+        })
+    }
+
+    #[napi(getter, catch_unwind)]
+    pub fn kind(&self) -> GeometryKind {
+        self.inner.kind()
+    }
+
+    /// Returns the coordinates of the point as a single list, the coordinates of the line string
+    /// as a single list, or the coordinates of each of the polygon rings as a separate list.
+    #[napi(catch_unwind)]
+    pub fn get_coordinates(&self) -> Vec<Vec<f64>> {
+        match &self.inner {
+            Geometry::Point(point) => vec![vec![point.x, point.y]],
+            Geometry::LineString(points) => vec![Point::to_coordinates(points)],
+            Geometry::Polygon(rings) => rings
+                .iter()
+                .map(|ring| Point::to_coordinates(ring))
+                .collect(),
+        }
+    }
+
+    /// Serializes the geometry into the WKB format.
+    #[napi(catch_unwind)]
+    pub fn to_buffer(&self) -> Buffer {
+        self.inner.to_bytes().into()
+    }
+
+    #[napi(js_name = "toString", catch_unwind)]
+    pub fn to_format(&self) -> String {
+        self.inner.to_string()
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+enum GeometryInvalid {
+    #[error("Invalid WKT format of the geometry: {0}")]
+    Format(String),
+    #[error("Coordinates of the geometry must be pairs of finite numbers")]
+    Coordinate,
+    #[error("Invalid WKB format of the geometry")]
+    Buffer,
+}
+
+impl From<GeometryInvalid> for napi::Error {
+    fn from(value: GeometryInvalid) -> Self {
+        js_typed_error(value, ErrorType::TypeError)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_format() {
+        for value in [
+            "POINT (1 2)",
+            "POINT (-1.5 0.25)",
+            "LINESTRING (30 10, 10 30, 40 40)",
+            "LINESTRING EMPTY",
+            "POLYGON ((30 10, 40 40, 20 40, 10 20, 30 10))",
+            "POLYGON ((35 10, 45 45, 15 40, 10 20, 35 10), (20 30, 35 35, 30 20, 20 30))",
+            "POLYGON EMPTY",
+        ] {
+            assert_eq!(Geometry::parse(value).unwrap().to_string(), value);
+        }
+        assert_eq!(
+            Geometry::parse("  polygon((1 1,3 1 , 2 3,1 1) ) ")
+                .unwrap()
+                .to_string(),
+            "POLYGON ((1 1, 3 1, 2 3, 1 1))"
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for value in [
+            "",
+            "POINT",
+            "POINT EMPTY",
+            "POINT (1)",
+            "POINT (1 2 3)",
+            "POINT (1 NaN)",
+            "POINT 1 2",
+            "CIRCLE (1 2)",
+            "LINESTRING (1 2,)",
+            "POLYGON (1 1, 3 1, 2 3, 1 1)",
+            "POLYGON ((1 1, 3 1, 2 3, 1 1)",
+            "POLYGON ((1 1, 3 1, 2 3, 1 1) (1 1, 2 2, 1 1))",
+        ] {
+            assert!(Geometry::parse(value).is_err(), "{value}");
+        }
+    }
+
+    #[test]
+    fn test_bytes() {
+        for value in [
+            "POINT (1 2)",
+            "LINESTRING (30 10, 10 30, 40 40)",
+            "LINESTRING EMPTY",
+            "POLYGON ((35 10, 45 45, 15 40, 10 20, 35 10), (20 30, 35 35, 30 20, 20 30))",
+            "POLYGON EMPTY",
+        ] {
+            let geometry = Geometry::parse(value).unwrap();
+            assert_eq!(Geometry::from_bytes(&geometry.to_bytes()), Ok(geometry));
+        }
+        let big_endian = [
+            [BIG_ENDIAN].as_slice(),
+            &1u32.to_be_bytes(),
+            &1f64.to_be_bytes(),
+            &2f64.to_be_bytes(),
+        ]
+        .concat();
+        assert_eq!(
+            Geometry::from_bytes(&big_endian),
+            Ok(Geometry::Point(Point { x: 1.0, y: 2.0 }))
+        );
+        assert_eq!(
+            Geometry::from_bytes(&[LITTLE_ENDIAN, 2, 0, 0, 0, 255, 255, 255, 255]),
+            Err(GeometryInvalid::Buffer)
+        );
+        assert_eq!(
+            Geometry::from_bytes(&[2, 1, 0, 0, 0]),
+            Err(GeometryInvalid::Buffer)
+        );
+    }
+}
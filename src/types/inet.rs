@@ -9,7 +9,7 @@ pub struct InetAddressWrapper {
 
 #[napi]
 impl InetAddressWrapper {
-    #[napi]
+    #[napi(catch_unwind)]
     pub fn new(buffer: BufferSlice) -> napi::Result<Self> {
         let buffer = buffer.as_ref();
         if let Ok(arr) = <[u8; 4]>::try_from(buffer) {
@@ -28,7 +28,7 @@ impl InetAddressWrapper {
         }
     }
 
-    #[napi]
+    #[napi(catch_unwind)]
     pub fn get_length(&self) -> u32 {
         match self.inet {
             IpAddr::V4(_) => 4,
@@ -36,7 +36,7 @@ impl InetAddressWrapper {
         }
     }
 
-    #[napi]
+    #[napi(catch_unwind)]
     pub fn get_version(&self) -> u32 {
         match self.inet {
             IpAddr::V4(_) => 4,
@@ -44,7 +44,7 @@ impl InetAddressWrapper {
         }
     }
 
-    #[napi]
+    #[napi(catch_unwind)]
     pub fn get_buffer(&self) -> Buffer {
         let buffer = match self.inet {
             IpAddr::V4(ip) => ip.octets().to_vec(),
@@ -40,7 +40,7 @@ pub struct LocalDateWrapper {
 #[napi]
 impl LocalDateWrapper {
     /// Create a new object from the day, month and year.
-    #[napi]
+    #[napi(catch_unwind)]
     pub fn new(day: i8, month: i8, year: i32) -> napi::Result<Self> {
         let date = Ymd::new(year, month, day)?;
 
@@ -54,7 +54,7 @@ impl LocalDateWrapper {
     }
 
     /// Create a new object from number of days since 01.01.1970.
-    #[napi]
+    #[napi(catch_unwind)]
     pub fn new_day(value: i32) -> napi::Result<Self> {
         let date = Ymd::from_days(value.into());
         Ok(LocalDateWrapper {
@@ -64,12 +64,12 @@ impl LocalDateWrapper {
         })
     }
 
-    #[napi]
+    #[napi(catch_unwind)]
     pub fn get_date(&self) -> Option<Ymd> {
         self.date.clone()
     }
 
-    #[napi(js_name = "toString")]
+    #[napi(js_name = "toString", catch_unwind)]
     pub fn to_format(&self) -> String {
         self.to_string()
     }
@@ -89,7 +89,7 @@ impl LocalDateWrapper {
     }
 
     /// Returns the number of days since 01.01.1970 based on a String representing the date.
-    #[napi]
+    #[napi(catch_unwind)]
     pub fn from_string(value: String) -> napi::Result<i32> {
         match value.chars().filter(|c| *c == '-').count() {
             d if d < 2 => match value.parse::<i32>() {
@@ -187,7 +187,7 @@ pub struct Ymd {
 
 impl Ymd {
     /// Create a new Ymd object
-    fn new(year: i32, month: i8, day: i8) -> Result<Ymd, DateInvalid> {
+    pub(crate) fn new(year: i32, month: i8, day: i8) -> Result<Ymd, DateInvalid> {
         if !(1..=12).contains(&month) {
             return Err(DateInvalid::Month);
         }
@@ -200,7 +200,7 @@ impl Ymd {
     }
 
     /// Counts the number of days since 01.01.1970.
-    fn to_days(&self) -> i32 {
+    pub(crate) fn to_days(&self) -> i32 {
         let mut total_days = 0;
 
         let number_day = DAY_IN_MONTH[self.month as usize - 1] // number of days from 1 January
@@ -231,7 +231,7 @@ impl Ymd {
     }
 
     /// Create a Ymd from the number of days since 01.01.1970.
-    fn from_days(mut n: i64) -> Option<Self> {
+    pub(crate) fn from_days(mut n: i64) -> Option<Self> {
         if !(MIN_JS_DATE..=MAX_JS_DATE).contains(&(n as i32)) {
             None
         } else {
@@ -287,7 +287,7 @@ impl Ymd {
     }
 
     /// Returns the number of days in a month.
-    fn days_in_month(month: i8, year: i32) -> i8 {
+    pub(crate) fn days_in_month(month: i8, year: i32) -> i8 {
         match month {
             1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
             4 | 6 | 9 | 11 => 30,
@@ -303,7 +303,7 @@ impl Ymd {
     }
 }
 #[derive(Error, Debug)]
-enum DateInvalid {
+pub(crate) enum DateInvalid {
     #[error("Invalid month")]
     Month,
     #[error("Invalid number of day")]
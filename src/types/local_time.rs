@@ -43,12 +43,12 @@ impl LocalTimeWrapper {
         }
     }
 
-    #[napi]
+    #[napi(catch_unwind)]
     pub fn get_value(&self) -> BigInt {
         self.value.clone()
     }
 
-    #[napi]
+    #[napi(catch_unwind)]
     pub fn new(total_nanoseconds: BigInt) -> napi::Result<Self> {
         let ns_value = bigint_to_i64(total_nanoseconds, "Nanoseconds must not overflow i64")?;
 
@@ -56,12 +56,12 @@ impl LocalTimeWrapper {
     }
 
     /// format: hh:MM:ss.ns
-    #[napi(js_name = "toString")]
+    #[napi(js_name = "toString", catch_unwind)]
     pub fn to_format(&self) -> String {
         self.to_string()
     }
 
-    #[napi]
+    #[napi(catch_unwind)]
     pub fn from_string(s: String) -> napi::Result<BigInt> {
         let lambda = |s: String| -> Result<i64, ParseIntError> {
             let parts: Vec<&str> = s.split(':').collect();
@@ -1,5 +1,7 @@
+pub mod date_range;
 pub mod duration;
 pub mod encoded_data;
+pub mod geometry;
 pub mod inet;
 pub mod local_date;
 pub mod local_time;
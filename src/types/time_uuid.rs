@@ -10,7 +10,7 @@ pub struct TimeUuidWrapper {
 
 #[napi]
 impl TimeUuidWrapper {
-    #[napi]
+    #[napi(catch_unwind)]
     pub fn get_buffer(&self) -> Buffer {
         Buffer::from(self.uuid.get_cql_uuid().into_bytes().to_vec())
     }
@@ -6,6 +6,8 @@ use napi::{
 };
 use scylla::{cluster::metadata::NativeType, frame::response::result::ColumnType};
 
+use crate::errors::{ErrorType, js_typed_error};
+
 /// Represents CQL types with their corresponding numeric values from the CQL protocol.
 #[derive(Clone)]
 #[napi]
@@ -109,7 +111,10 @@ impl ToNapiValue for ComplexType<'_> {
                         NativeType::Uuid => CqlType::Uuid,
                         NativeType::Varint => CqlType::Varint,
                         other => {
-                            unimplemented!("Missing implementation for CQL native type {:?}", other)
+                            return Err(js_typed_error(
+                                format!("Missing implementation for CQL native type {other:?}"),
+                                ErrorType::NotSupportedError,
+                            ));
                         }
                     },
                 )?;
@@ -127,7 +132,10 @@ impl ToNapiValue for ComplexType<'_> {
                         (CqlType::Set, column_type, None)
                     }
                     other => {
-                        unimplemented!("Missing implementation for CQL Collection type {:?}", other)
+                        return Err(js_typed_error(
+                            format!("Missing implementation for CQL Collection type {other:?}"),
+                            ErrorType::NotSupportedError,
+                        ));
                     }
                 };
                 obj.set_named_property(base_type_name, name)?;
@@ -176,7 +184,12 @@ impl ToNapiValue for ComplexType<'_> {
                         .collect::<Vec<_>>(),
                 )?;
             }
-            other => unimplemented!("Missing implementation for CQL type {:?}", other),
+            other => {
+                return Err(js_typed_error(
+                    format!("Missing implementation for CQL type {other:?}"),
+                    ErrorType::NotSupportedError,
+                ));
+            }
         }
 
         Ok(obj.raw())
@@ -8,7 +8,7 @@ pub struct UuidWrapper {
 
 #[napi]
 impl UuidWrapper {
-    #[napi]
+    #[napi(catch_unwind)]
     pub fn new(buffer: BufferSlice) -> napi::Result<Self> {
         match Uuid::from_slice(buffer.as_ref()) {
             Err(_) => Err(Error::new(Status::InvalidArg, "Invalid uuid buffer")),
@@ -16,7 +16,7 @@ impl UuidWrapper {
         }
     }
 
-    #[napi]
+    #[napi(catch_unwind)]
     pub fn get_buffer(&self) -> Buffer {
         Buffer::from(self.uuid.as_bytes().to_vec())
     }
@@ -31,7 +31,7 @@ impl UuidWrapper {
     }
 }
 
-#[napi]
+#[napi(catch_unwind)]
 pub fn get_random_uuid_v4() -> Buffer {
     Buffer::from(Uuid::new_v4().as_bytes().as_slice())
 }
@@ -1054,9 +1054,7 @@ describe("Client @SERVER_API", function () {
                 });
             },
         );
-        // Would require error throwing refactor
-        // TODO: Fix this test
-        it("should not use keyspace if set on options for lower protocol versions", function () {
+        it("should use keyspace if set on options", function () {
             const client = newInstance({});
             const insertQuery =
                 "INSERT INTO %s (id, time, double_sample) VALUES (?, ?, ?)";
@@ -1075,19 +1073,67 @@ describe("Client @SERVER_API", function () {
             ];
             return client
                 .batch(queries, { prepare: true, keyspace: keyspace })
+                .then(() =>
+                    client.execute(
+                        util.format(
+                            "SELECT double_sample FROM %s WHERE id = ?",
+                            table1,
+                        ),
+                        [id],
+                    ),
+                )
                 .then((result) => {
-                    throw new Error("should have failed");
+                    assert.strictEqual(result.rows.length, 2);
+                    assert.ok(helper.find(result.rows, "double_sample", 1000));
+                    assert.ok(helper.find(result.rows, "double_sample", 2000));
                 })
-                .catch(function (err) {
-                    assert.ok(err);
-                    assert.ok(
-                        err.message.includes(
-                            "Preparation failed on every connection from the selected pool.",
-                        ),
-                    );
-                    // helper.assertInstanceOf(err, errors.ResponseError);
-                    return client.shutdown();
-                });
+                .finally(() => client.shutdown());
+        });
+        it("should return the existing row of a conditional batch that was not applied", async function () {
+            const client = newInstance();
+            helper.afterThisTest(() => client.shutdown());
+            const id = types.Uuid.random();
+            const time = types.timeuuid();
+            const insertQuery = util.format(
+                "INSERT INTO %s (id, time, text_sample) VALUES (?, ?, ?) IF NOT EXISTS",
+                table1,
+            );
+            const queries = [
+                { query: insertQuery, params: [id, time, "first"] },
+                {
+                    query: util.format(
+                        "UPDATE %s SET int_sample = ? WHERE id = ? AND time = ? IF text_sample = ?",
+                        table1,
+                    ),
+                    params: [1, id, time, "first"],
+                },
+            ];
+
+            const applied = await client.batch(queries.slice(0, 1), {
+                prepare: true,
+            });
+            assert.strictEqual(applied.wasApplied(), true);
+            assert.strictEqual(applied.getExistingRow(), null);
+
+            const notApplied = await client.batch(
+                [{ query: insertQuery, params: [id, time, "second"] }],
+                { prepare: true },
+            );
+            assert.strictEqual(notApplied.wasApplied(), false);
+            const existingRow = notApplied.getExistingRow();
+            assert.strictEqual(existingRow["[applied]"], false);
+            assert.strictEqual(existingRow.text_sample, "first");
+
+            if (!helper.getServerInfo().isScylla) {
+                return;
+            }
+            // Conditional batches are routed as LWT, always to the primary replica first
+            const coordinators = new Set();
+            for (let i = 0; i < 10; i++) {
+                const result = await client.batch(queries, { prepare: true });
+                coordinators.add(result.info.queriedHost);
+            }
+            assert.strictEqual(coordinators.size, 1);
         });
     });
 });
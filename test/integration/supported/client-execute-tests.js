@@ -1596,6 +1596,89 @@ describe("Client @SERVER_API", function () {
             });
         });
 
+        describe("with maps with uuid, timestamp and tuple keys", function () {
+            const id = types.Uuid.random();
+            const firstUuid = types.Uuid.random();
+            const secondUuid = types.Uuid.random();
+            const firstDate = new Date(1577836800000);
+            const secondDate = new Date(1623760200000);
+
+            before(async function () {
+                const client = setupInfo.client;
+                await client.execute(
+                    "CREATE TABLE tbl_map_keys (id uuid PRIMARY KEY, uuid_map map<uuid,text>, timestamp_map map<timestamp,int>, tuple_map map<frozen<tuple<int,text>>,text>)",
+                );
+                await client.execute(
+                    `INSERT INTO tbl_map_keys (id, uuid_map, timestamp_map, tuple_map) VALUES (${id}, ` +
+                        `{${firstUuid}: 'first', ${secondUuid}: 'second'}, ` +
+                        `{${firstDate.getTime()}: 1, ${secondDate.getTime()}: 2}, ` +
+                        "{(1, 'a'): 'first', (2, 'b'): 'second'})",
+                );
+            });
+
+            [false, true].forEach((prepare) => {
+                it(`should decode the keys into driver types with the Map constructor with prepare: ${prepare}`, async function () {
+                    const rs = await setupInfo.client.execute(
+                        "SELECT * FROM tbl_map_keys WHERE id = ?",
+                        [id],
+                        { prepare, encoding: { map: Map } },
+                    );
+                    const row = rs.first();
+
+                    const uuidMap = row["uuid_map"];
+                    assert.ok(uuidMap instanceof Map);
+                    uuidMap.forEach((_, key) =>
+                        assert.ok(key instanceof types.Uuid),
+                    );
+                    assert.deepStrictEqual(
+                        new Map(
+                            Array.from(uuidMap, ([key, value]) => [
+                                key.toString(),
+                                value,
+                            ]),
+                        ),
+                        new Map([
+                            [firstUuid.toString(), "first"],
+                            [secondUuid.toString(), "second"],
+                        ]),
+                    );
+
+                    const timestampMap = row["timestamp_map"];
+                    assert.ok(timestampMap instanceof Map);
+                    timestampMap.forEach((_, key) =>
+                        assert.ok(key instanceof Date),
+                    );
+                    assert.deepStrictEqual(
+                        Array.from(timestampMap, ([key, value]) => [
+                            key.getTime(),
+                            value,
+                        ]),
+                        [
+                            [firstDate.getTime(), 1],
+                            [secondDate.getTime(), 2],
+                        ],
+                    );
+
+                    const tupleMap = row["tuple_map"];
+                    assert.ok(tupleMap instanceof Map);
+                    tupleMap.forEach((_, key) =>
+                        assert.ok(key instanceof types.Tuple),
+                    );
+                    assert.deepStrictEqual(
+                        Array.from(tupleMap, ([key, value]) => [
+                            key.get(0),
+                            key.get(1),
+                            value,
+                        ]),
+                        [
+                            [1, "a", "first"],
+                            [2, "b", "second"],
+                        ],
+                    );
+                });
+            });
+        });
+
         // No support for named parameters
         // TODO: Fix this test
         /* describe("with named parameters", function () {
@@ -2194,24 +2277,286 @@ describe("Client @SERVER_API", function () {
                     );
                 });
             });
+
+            it("should return the existing row when not applied", async function () {
+                const query = util.format(
+                    "UPDATE %s SET text_sample = ? WHERE id = ? IF text_sample = ?",
+                    table,
+                );
+                for (const prepare of [false, true]) {
+                    const result = await client.execute(
+                        query,
+                        ["new value", id, "other value"],
+                        { prepare },
+                    );
+                    assert.strictEqual(result.wasApplied(), false);
+                    const existingRow = result.getExistingRow();
+                    assert.strictEqual(existingRow["[applied]"], false);
+                    assert.strictEqual(existingRow.text_sample, "val");
+                }
+            });
+
+            it("should not return the existing row when applied", async function () {
+                const query = util.format(
+                    "UPDATE %s SET text_sample = ? WHERE id = ? IF text_sample = ?",
+                    table,
+                );
+                const result = await client.execute(query, ["val", id, "val"], {
+                    prepare: true,
+                });
+                assert.strictEqual(result.wasApplied(), true);
+                assert.strictEqual(result.getExistingRow(), null);
+            });
         });
 
         numericTests(keyspace, false);
         pagingTests(keyspace, false);
 
-        // No support for keyspace
-        // TODO: Fix this test
-        /* it("should not use keyspace if set on options for lower protocol versions", function () {
+        it("should use keyspace if set on options", async function () {
             const client = setupInfo.client;
-            return client
-                .execute("select * from local", null, { keyspace: "system" })
-                .then((result) => {
-                    throw new Error("should have failed");
-                })
-                .catch(function (err) {
-                    helper.assertInstanceOf(err, errors.ResponseError);
+            for (const prepare of [false, true]) {
+                const result = await client.execute(
+                    "select key from local",
+                    null,
+                    { keyspace: "system", prepare },
+                );
+                assert.strictEqual(result.rows.length, 1);
+                assert.strictEqual(result.rows[0].key, "local");
+            }
+            // The keyspace of the client is not changed
+            assert.strictEqual(client.keyspace, setupInfo.keyspace);
+        });
+
+        it("should use the keyspaces set on options of different statements", async function () {
+            const client = setupInfo.client;
+            // The second keyspace name is case sensitive
+            const keyspaces = [helper.getRandomName("ks"), "ksMixedCase"];
+            for (const name of keyspaces) {
+                const keyspace = `"${name}"`;
+                await client.execute(helper.createKeyspaceCql(keyspace, 1));
+                await client.execute(
+                    `CREATE TABLE ${keyspace}.tbl (id int PRIMARY KEY, value text)`,
+                );
+                await client.execute(
+                    `INSERT INTO ${keyspace}.tbl (id, value) VALUES (1, '${name}')`,
+                );
+            }
+            try {
+                for (const prepare of [false, true]) {
+                    for (const keyspace of keyspaces) {
+                        const result = await client.execute(
+                            "SELECT value FROM tbl WHERE id = ?",
+                            [1],
+                            { keyspace, prepare },
+                        );
+                        assert.strictEqual(result.rows.length, 1);
+                        assert.strictEqual(result.rows[0].value, keyspace);
+                    }
+                }
+                // The keyspace of the client is not changed
+                assert.strictEqual(client.keyspace, setupInfo.keyspace);
+            } finally {
+                for (const name of keyspaces) {
+                    await client.execute(`DROP KEYSPACE "${name}"`);
+                }
+            }
+        });
+
+        it("should execute the statement on the target host and shard", async function () {
+            const client = setupInfo.client;
+            const hosts = client.hosts.values();
+            assert.ok(hosts.length > 0);
+            for (const host of hosts) {
+                for (const prepare of [false, true]) {
+                    for (const shard of [undefined, 0]) {
+                        const result = await client.execute(
+                            "SELECT key FROM system.local",
+                            null,
+                            { host, shard, prepare },
+                        );
+                        assert.strictEqual(
+                            result.info.queriedHost,
+                            host.address,
+                        );
+                    }
+                }
+            }
+        });
+
+        it("should reject invalid shards of the target host", async function () {
+            const client = setupInfo.client;
+            const host = client.hosts.values()[0];
+            for (const shard of [-1, 1.5, "0"]) {
+                await assert.rejects(
+                    client.execute("SELECT key FROM system.local", null, {
+                        host,
+                        shard,
+                    }),
+                    errors.ArgumentError,
+                );
+            }
+            // Shard that doesn't exist on the host
+            await assert.rejects(
+                client.execute("SELECT key FROM system.local", null, {
+                    host,
+                    shard: 100000,
+                }),
+                errors.ArgumentError,
+            );
+            // Shard without the host
+            await assert.rejects(
+                client.execute("SELECT key FROM system.local", null, {
+                    shard: 0,
+                }),
+                errors.ArgumentError,
+            );
+        });
+
+        for (const compression of ["lz4", "snappy"]) {
+            it(`should execute queries with ${compression} compression`, async function () {
+                const client = newInstance({
+                    keyspace: setupInfo.keyspace,
+                    compression,
                 });
-        }); */
+                await client.connect();
+                try {
+                    assert.strictEqual(
+                        client.configuredCompression,
+                        compression,
+                    );
+                    const id = types.Uuid.random();
+                    // Value long enough to be compressed
+                    const text = "compressed ".repeat(1000);
+                    for (const prepare of [false, true]) {
+                        await client.execute(
+                            `INSERT INTO ${table} (id, text_sample) VALUES (?, ?)`,
+                            [id, text],
+                            { prepare },
+                        );
+                        const result = await client.execute(
+                            `SELECT text_sample FROM ${table} WHERE id = ?`,
+                            [id],
+                            { prepare },
+                        );
+                        assert.strictEqual(result.first().text_sample, text);
+                    }
+                } finally {
+                    await client.shutdown();
+                }
+            });
+        }
+
+        it("should provide the execution info of the result", async function () {
+            const client = setupInfo.client;
+            const hosts = client.hosts.values().map((host) => host.address);
+            for (const prepare of [false, true]) {
+                const result = await client.execute(
+                    "SELECT key FROM system.local",
+                    null,
+                    { prepare, consistency: types.consistencies.one },
+                );
+                const info = result.info;
+                assert.ok(hosts.includes(info.queriedHost));
+                assert.strictEqual(
+                    info.achievedConsistency,
+                    types.consistencies.one,
+                );
+                // Attempts may use the shard-aware port of the host
+                const triedHosts = Object.keys(info.triedHosts);
+                assert.strictEqual(triedHosts.length, 1);
+                assert.strictEqual(info.triedHosts[triedHosts[0]], 1);
+                assert.strictEqual(info.speculativeExecutions, 0);
+                assert.strictEqual(typeof info.latency, "number");
+                assert.ok(info.latency > 0);
+            }
+            // Default consistency of the Rust driver
+            const result = await client.execute("SELECT key FROM system.local");
+            assert.strictEqual(
+                result.info.achievedConsistency,
+                types.consistencies.localQuorum,
+            );
+        });
+
+        it("should use keyspace set on options when paging", async function () {
+            const client = setupInfo.client;
+            const stream = client.stream("select key from local", null, {
+                keyspace: "system",
+                prepare: true,
+                fetchSize: 1,
+            });
+            const rows = [];
+            for await (const row of stream) {
+                rows.push(row);
+            }
+            assert.strictEqual(rows.length, 1);
+        });
+
+        it("should change the keyspace with USE statement", async function () {
+            const client = newInstance({ keyspace: setupInfo.keyspace });
+            await client.connect();
+            try {
+                await client.execute("USE system");
+                assert.strictEqual(client.keyspace, "system");
+                const result = await client.execute("select key from local");
+                assert.strictEqual(result.rows.length, 1);
+            } finally {
+                await client.shutdown();
+            }
+        });
+
+        it("should change the keyspace of the session with useKeyspace", async function () {
+            const client = newInstance({ keyspace: setupInfo.keyspace });
+            await client.connect();
+            try {
+                await client.rustClient.useKeyspace("system", false);
+                assert.strictEqual(client.keyspace, "system");
+                const result = await client.execute(
+                    "select key from local",
+                    null,
+                    { prepare: true },
+                );
+                assert.strictEqual(result.rows.length, 1);
+            } finally {
+                await client.shutdown();
+            }
+        });
+
+        it("should abort pending requests with the signal", async function () {
+            const client = setupInfo.client;
+            for (const prepare of [false, true]) {
+                const controller = new AbortController();
+                // A new query is prepared, to abort the pending preparation
+                const request = client.execute(
+                    `SELECT key FROM system.local WHERE key = '${helper.getRandomName()}'`,
+                    null,
+                    { prepare, signal: controller.signal },
+                );
+                controller.abort();
+                const err = await request.then(
+                    () => assert.fail("It should have been aborted"),
+                    (err) => err,
+                );
+                helper.assertInstanceOf(err, errors.AbortError);
+            }
+            // The client can still be used after aborting the requests
+            const result = await client.execute(helper.queries.basic);
+            assert.strictEqual(result.rows.length, 1);
+        });
+
+        it("should reject requests with an already aborted signal", async function () {
+            const controller = new AbortController();
+            controller.abort();
+            const err = await setupInfo.client
+                .execute(helper.queries.basic, null, {
+                    prepare: true,
+                    signal: controller.signal,
+                })
+                .then(
+                    () => assert.fail("It should have been aborted"),
+                    (err) => err,
+                );
+            helper.assertInstanceOf(err, errors.AbortError);
+        });
     });
 });
 
@@ -1186,8 +1186,101 @@ describe("Client", function () {
         });
     }); */
 
-    // No support for shutdown
-    /* describe("#shutdown()", function () {}); */
+    describe("#shutdown()", function () {
+        helper.setup(1, { initClient: false });
+
+        it("should let the requests in progress complete", async () => {
+            const client = newInstance();
+            await client.connect();
+
+            const requests = Array.from({ length: 50 }, () =>
+                client.execute(helper.queries.basic),
+            );
+            await client.shutdown();
+
+            const results = await Promise.all(requests);
+            results.forEach((rs) => assert.lengthOf(rs.rows, 1));
+        });
+
+        it("should reject requests made during and after the shutdown with ShutdownError", async () => {
+            const client = newInstance();
+            await client.connect();
+
+            const shutdown = client.shutdown();
+            const duringShutdown = client.execute(helper.queries.basic).then(
+                () => assert.fail("It should have been rejected"),
+                (err) => err,
+            );
+            await shutdown;
+
+            helper.assertInstanceOf(await duringShutdown, errors.ShutdownError);
+            const err = await client
+                .execute(helper.queries.basic, [], { prepare: true })
+                .then(
+                    () => assert.fail("It should have been rejected"),
+                    (err) => err,
+                );
+            helper.assertInstanceOf(err, errors.ShutdownError);
+        });
+
+        it("should complete when the connection attempt in progress fails", async () => {
+            const client = newInstance({
+                contactPoints: ["1.1.1.1"],
+                socketOptions: { connectTimeout: 1000 },
+            });
+            const connect = client.connect().then(
+                () => assert.fail("It should have been rejected"),
+                (err) => err,
+            );
+            await client.shutdown();
+
+            helper.assertInstanceOf(await connect, Error);
+            assert.isFalse(client.connected);
+        });
+
+        it("should have no effect when called again", async () => {
+            const client = newInstance();
+            await client.connect();
+            await client.shutdown();
+            await client.shutdown();
+        });
+
+        it("should not wait longer than shutdownTimeout for the requests in progress", async () => {
+            const client = newInstance({
+                shutdownTimeout: 500,
+                socketOptions: { readTimeout: 0 },
+            });
+            await client.connect();
+            const warnings = [];
+            client.on("log", (level, className, message) => {
+                if (level === "warning") {
+                    warnings.push(message);
+                }
+            });
+
+            await util.promisify(helper.ccmHelper.pauseNode)(1);
+            const request = client.execute(helper.queries.basic).then(
+                () => null,
+                (err) => err,
+            );
+            try {
+                const start = process.hrtime.bigint();
+                await client.shutdown();
+                const elapsedMs = Number(process.hrtime.bigint() - start) / 1e6;
+
+                assert.isBelow(elapsedMs, 5000);
+                assert.ok(
+                    warnings.some((w) =>
+                        w.includes("Shutdown did not complete"),
+                    ),
+                );
+            } finally {
+                await util.promisify(helper.ccmHelper.resumeNode)(1);
+            }
+            // The request is still allowed to complete once the node is back
+            assert.isNull(await request);
+        });
+    });
 });
 
 /**
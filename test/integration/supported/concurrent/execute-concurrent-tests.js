@@ -5,7 +5,8 @@ const fs = require("fs");
 const types = require("../../../../lib/types");
 const errors = require("../../../../lib/errors");
 const helper = require("../../../test-helper");
-const Transform = require("stream").Transform;
+const Client = require("../../../../lib/client");
+const { PassThrough, Transform } = require("stream");
 const Uuid = types.Uuid;
 const executeConcurrent =
     require("../../../../lib/concurrent").executeConcurrent;
@@ -286,6 +287,27 @@ describe("executeConcurrent()", function () {
                         });
                 }),
             ));
+
+        it("should not block the shutdown when the stream never ends", async () => {
+            const tempClient = new Client(
+                helper.getOptions({ keyspace: setupInfo.keyspace }),
+            );
+            await tempClient.connect();
+            // Stream that provides a single item and then never ends
+            const stream = new PassThrough({ objectMode: true });
+            stream.write(getParameterValues(Uuid.random(), 1)[0]);
+
+            const execution = executeConcurrent(
+                tempClient,
+                insertQuery1,
+                stream,
+            ).catch((err) => err);
+            await new Promise((resolve) => setTimeout(resolve, 500));
+            await tempClient.shutdown();
+            stream.write(getParameterValues(Uuid.random(), 1)[0]);
+
+            helper.assertInstanceOf(await execution, errors.ShutdownError);
+        });
     });
 
     describe("with different queries and parameters", () => {
@@ -0,0 +1,94 @@
+"use strict";
+const assert = require("assert");
+const helper = require("../../test-helper");
+const { DateRange } = require("../../../lib/datastax/search");
+const { LineString, Point, Polygon } = require("../../../lib/geometry");
+
+// Custom types are not supported by the driver, see docs/src/custom_types.md.
+// These tests document the limitation on the servers that know the custom types.
+describe("Custom types @SERVER_API", function () {
+    this.timeout(120000);
+
+    const setupInfo = helper.setup(1);
+    const client = setupInfo.client;
+
+    /**
+     * Creates the table with a column of the custom type,
+     * skips the tests when the custom type is not known by the server.
+     * @param {String} table
+     * @param {String} customType
+     */
+    const createTable = (table, customType) =>
+        async function () {
+            try {
+                await client.execute(
+                    `CREATE TABLE ${table} (id int PRIMARY KEY, value '${customType}')`,
+                );
+            } catch (err) {
+                this.skip();
+            }
+        };
+
+    describe("DateRangeType", function () {
+        const table = "tbl_date_range";
+
+        before(
+            createTable(table, "org.apache.cassandra.db.marshal.DateRangeType"),
+        );
+
+        it("should fail to read the values of the column", async function () {
+            await assert.rejects(
+                client.execute(`SELECT id, value FROM ${table}`),
+            );
+        });
+
+        it("should fail to insert DateRange parameters", async function () {
+            await assert.rejects(
+                client.execute(
+                    `INSERT INTO ${table} (id, value) VALUES (?, ?)`,
+                    [1, DateRange.fromString("[2015-02 TO 2016]")],
+                ),
+            );
+        });
+    });
+
+    [
+        ["PointType", new Point(1, 2)],
+        ["LineStringType", new LineString(new Point(1, 2), new Point(3, 4))],
+        [
+            "PolygonType",
+            new Polygon([
+                new Point(1, 1),
+                new Point(3, 1),
+                new Point(2, 3),
+                new Point(1, 1),
+            ]),
+        ],
+    ].forEach(([customType, value]) => {
+        describe(customType, function () {
+            const table = `tbl_${customType.toLowerCase()}`;
+
+            before(
+                createTable(
+                    table,
+                    `org.apache.cassandra.db.marshal.${customType}`,
+                ),
+            );
+
+            it("should fail to read the values of the column", async function () {
+                await assert.rejects(
+                    client.execute(`SELECT id, value FROM ${table}`),
+                );
+            });
+
+            it(`should fail to insert ${value.constructor.name} parameters`, async function () {
+                await assert.rejects(
+                    client.execute(
+                        `INSERT INTO ${table} (id, value) VALUES (?, ?)`,
+                        [1, value],
+                    ),
+                );
+            });
+        });
+    });
+});
@@ -172,5 +172,82 @@ module.exports = function (keyspace, prepare) {
                 assert.lengthOf(rows, 0);
             });
         }
+
+        context("with Arrow export", function () {
+            const continuationMarker = Buffer.from([0xff, 0xff, 0xff, 0xff]);
+            const endOfStream = Buffer.from([
+                0xff, 0xff, 0xff, 0xff, 0, 0, 0, 0,
+            ]);
+
+            it("should export the rows of the current page", async () => {
+                const rs = await client.execute(query, [keyB], {
+                    prepare,
+                    fetchSize: 2,
+                });
+                const stream = rs.toArrowIpc();
+
+                assert.instanceOf(stream, Buffer);
+                assert.isTrue(stream.subarray(0, 4).equals(continuationMarker));
+                assert.isTrue(stream.subarray(-8).equals(endOfStream));
+                // The schema contains the column names
+                ["id1", "id2", "value"].forEach((name) =>
+                    assert.isTrue(stream.includes(name)),
+                );
+                // Only the values of the current page are exported
+                assert.isTrue(stream.includes("b0"));
+                assert.isTrue(stream.includes("b1"));
+                assert.isFalse(stream.includes("b2"));
+                // The rows are still available
+                assert.lengthOf(rs.rows, 2);
+            });
+
+            it("should export the rows of all the pages as parts of a single stream", async () => {
+                const rs = await client.execute(query, [keyB], {
+                    prepare,
+                    fetchSize: 2,
+                });
+                const parts = [];
+                for await (const part of rs.arrowIpcPages()) {
+                    parts.push(part);
+                }
+
+                assert.lengthOf(parts, Math.ceil(rowsInPartitionB / 2));
+                parts.forEach((part, index) => {
+                    assert.isTrue(
+                        part.subarray(0, 4).equals(continuationMarker),
+                    );
+                    assert.strictEqual(
+                        part.subarray(-8).equals(endOfStream),
+                        index === parts.length - 1,
+                    );
+                    // Only the first part starts with the schema
+                    assert.strictEqual(part.includes("value"), index === 0);
+                });
+                const stream = Buffer.concat(parts);
+                for (let n = 0; n < rowsInPartitionB; n++) {
+                    assert.isTrue(stream.includes(`b${n}`));
+                }
+            });
+
+            it("should not export VOID results", async () => {
+                const options = { prepare };
+
+                if (!prepare) {
+                    options.hints = ["text", "int", "text"];
+                }
+
+                const rs = await client.execute(
+                    insertQuery,
+                    ["new-key", 2, "new!"],
+                    options,
+                );
+                assert.isNull(rs.toArrowIpc());
+                const parts = [];
+                for await (const part of rs.arrowIpcPages()) {
+                    parts.push(part);
+                }
+                assert.lengthOf(parts, 0);
+            });
+        });
     });
 };
@@ -74,6 +74,9 @@ async function myTest(): Promise<any> {
     .on("data", () => { })
     .on("error", (err) => console.error(err));
 
+  const reconnectionMetrics: { failedAttempts: number; reconnections: number } =
+    client.getReconnectionMetrics();
+
   promise = client.shutdown();
   client.shutdown((err) => (error = err));
 
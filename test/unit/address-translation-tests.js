@@ -0,0 +1,83 @@
+"use strict";
+const { assert } = require("chai");
+const clientOptions = require("../../lib/client-options");
+const policies = require("../../lib/policies");
+const rust = require("../../index");
+
+const { AddressTranslator } = policies.addressResolution;
+
+/**
+ * @param {AddressTranslator} translator
+ * @returns {Function} Translation function passed to the Rust session
+ */
+function getRustTranslator(translator) {
+    return clientOptions.setRustOptions({
+        contactPoints: ["127.0.0.1"],
+        policies: { addressResolution: translator },
+    }).addressTranslator;
+}
+
+describe("JS address translator in Rust", function () {
+    class PortTranslator extends AddressTranslator {
+        translate(address, port, callback) {
+            callback(address + ":" + (port + 10000));
+        }
+    }
+
+    it("should translate IPv4 addresses", async function () {
+        const translated = await rust.testsJsAddressTranslation(
+            getRustTranslator(new PortTranslator()),
+            "10.0.0.1:9042",
+            1000,
+        );
+        assert.strictEqual(translated, "10.0.0.1:19042");
+    });
+
+    it("should accept IPv6 addresses without brackets", async function () {
+        // Translators concatenate the address and the port
+        const translated = await rust.testsJsAddressTranslation(
+            getRustTranslator(new PortTranslator()),
+            "[2001:db8::1]:9042",
+            1000,
+        );
+        assert.strictEqual(translated, "[2001:db8::1]:19042");
+    });
+
+    it("should fail when the translator does not call back", async function () {
+        class SilentTranslator extends AddressTranslator {
+            translate() {}
+        }
+        let error;
+        try {
+            await rust.testsJsAddressTranslation(
+                getRustTranslator(new SilentTranslator()),
+                "10.0.0.1:9042",
+                50,
+            );
+        } catch (err) {
+            error = err;
+        }
+        assert.instanceOf(error, Error);
+        assert.include(error.message, "did not respond");
+    });
+
+    it("should fail when the translated address is invalid", async function () {
+        class InvalidTranslator extends AddressTranslator {
+            translate(address, port, callback) {
+                callback("not an address");
+            }
+        }
+        let error;
+        try {
+            await rust.testsJsAddressTranslation(
+                getRustTranslator(new InvalidTranslator()),
+                "10.0.0.1:9042",
+                1000,
+            );
+        } catch (err) {
+            error = err;
+        }
+        assert.instanceOf(error, Error);
+        assert.include(error.message, "is invalid");
+    });
+});
@@ -0,0 +1,39 @@
+"use strict";
+const { assert } = require("chai");
+const rust = require("../../index");
+const { ResultSet } = require("../../lib/types");
+const errors = require("../../lib/errors");
+
+describe("Arrow export", function () {
+    describe("Rust conversion of the nested values", function () {
+        it("should convert lists", function () {
+            rust.testsArrowList();
+        });
+
+        it("should convert maps", function () {
+            rust.testsArrowMap();
+        });
+
+        it("should convert user defined types", function () {
+            rust.testsArrowUdt();
+        });
+
+        it("should convert tuples", function () {
+            rust.testsArrowTuple();
+        });
+    });
+
+    describe("ResultSet", function () {
+        it("should not support the export of the legacy result set", async function () {
+            const result = new ResultSet({ rows: [] }, null);
+            assert.throws(() => result.toArrowIpc(), errors.NotSupportedError);
+            let error;
+            try {
+                await result.arrowIpcPages().next();
+            } catch (err) {
+                error = err;
+            }
+            assert.instanceOf(error, errors.NotSupportedError);
+        });
+    });
+});
@@ -0,0 +1,39 @@
+"use strict";
+const { assert } = require("chai");
+const { mapNapiError } = require("../../lib/new-utils");
+const { AbortError } = require("../../lib/errors");
+const rust = require("../../index");
+
+describe("CancellationHandle", function () {
+    /**
+     * @param {Promise} request
+     * @returns {Promise<Error>} The error the request was rejected with
+     */
+    async function getError(request) {
+        try {
+            await request;
+        } catch (err) {
+            return mapNapiError(err);
+        }
+        assert.fail("The request should have been rejected");
+    }
+
+    it("should abort a pending request", async function () {
+        const cancellation = new rust.CancellationHandle();
+        const request = rust.testsPendingRequest(cancellation);
+        setTimeout(() => cancellation.cancel(), 20);
+
+        const err = await getError(request);
+        assert.instanceOf(err, AbortError);
+        assert.strictEqual(err.message, "The request was aborted");
+        assert.isTrue(cancellation.isCancelled());
+    });
+
+    it("should not start a request with an already cancelled handle", async function () {
+        const cancellation = new rust.CancellationHandle();
+        cancellation.cancel();
+
+        const err = await getError(rust.testsPendingRequest(cancellation));
+        assert.instanceOf(err, AbortError);
+    });
+});
@@ -0,0 +1,353 @@
+"use strict";
+const { assert } = require("chai");
+const clientOptions = require("../../lib/client-options");
+const policies = require("../../lib/policies");
+const errors = require("../../lib/errors");
+const types = require("../../lib/types");
+const rust = require("../../index");
+
+describe("client options", function () {
+    describe("setRustOptions()", function () {
+        /**
+         * @param {Object} options Options provided by the user
+         * @returns {Object} Options passed to the Rust session
+         */
+        function getRustOptions(options) {
+            return clientOptions.setRustOptions({
+                contactPoints: ["127.0.0.1"],
+                ...options,
+            });
+        }
+
+        it("should map the constant reconnection policy", function () {
+            const rustOptions = getRustOptions({
+                policies: {
+                    reconnection:
+                        new policies.reconnection.ConstantReconnectionPolicy(
+                            300,
+                        ),
+                },
+            });
+            assert.deepEqual(rustOptions.reconnectionPolicy, {
+                kind: rust.ReconnectionPolicyKind.Constant,
+                delay: 300,
+            });
+        });
+
+        it("should map the exponential reconnection policy", function () {
+            const rustOptions = getRustOptions({
+                policies: {
+                    reconnection:
+                        new policies.reconnection.ExponentialReconnectionPolicy(
+                            100,
+                            5000,
+                        ),
+                },
+            });
+            assert.deepEqual(rustOptions.reconnectionPolicy, {
+                kind: rust.ReconnectionPolicyKind.Exponential,
+                baseDelay: 100,
+                maxDelay: 5000,
+                startWithNoDelay: false,
+            });
+        });
+
+        it("should map the exponential reconnection policy starting with no delay", function () {
+            const rustOptions = getRustOptions({
+                policies: {
+                    reconnection:
+                        new policies.reconnection.ExponentialReconnectionPolicy(
+                            100,
+                            5000,
+                            true,
+                        ),
+                },
+            });
+            assert.isTrue(rustOptions.reconnectionPolicy.startWithNoDelay);
+        });
+
+        it("should map the default reconnection policy", function () {
+            const expected = {
+                kind: rust.ReconnectionPolicyKind.Exponential,
+                baseDelay: 1000,
+                maxDelay: 10 * 60 * 1000,
+                startWithNoDelay: false,
+            };
+            const extendedOptions = clientOptions.extend({
+                contactPoints: ["127.0.0.1"],
+            });
+            assert.deepEqual(
+                clientOptions.setRustOptions(extendedOptions)
+                    .reconnectionPolicy,
+                expected,
+            );
+            // Client maps the options provided by the user
+            assert.deepEqual(getRustOptions({}).reconnectionPolicy, expected);
+        });
+
+        it("should map the pooling options", function () {
+            const rustOptions = getRustOptions({
+                pooling: {
+                    coreConnectionsPerHost: { [types.distance.local]: 3 },
+                    disallowShardAwarePort: true,
+                    shardAwareLocalPortRange: { min: 50000, max: 60000 },
+                    heartBeatInterval: 1000,
+                    heartBeatTimeout: 0,
+                },
+            });
+            assert.deepEqual(rustOptions.pooling, {
+                coreConnectionsPerHost: 3,
+                coreConnectionsPerShard: undefined,
+                disallowShardAwarePort: true,
+                shardAwareLocalPortRange: { min: 50000, max: 60000 },
+                heartBeatInterval: 1000,
+                heartBeatTimeout: 0,
+            });
+        });
+
+        it("should map the connections per shard", function () {
+            const rustOptions = getRustOptions({
+                pooling: { coreConnectionsPerShard: 2 },
+            });
+            assert.strictEqual(rustOptions.pooling.coreConnectionsPerShard, 2);
+            assert.isUndefined(rustOptions.pooling.coreConnectionsPerHost);
+        });
+
+        it("should ignore the max requests per connection", function () {
+            const rustOptions = getRustOptions({
+                pooling: { maxRequestsPerConnection: 100 },
+            });
+            assert.notProperty(rustOptions.pooling, "maxRequestsPerConnection");
+        });
+
+        it("should reject custom reconnection policies", function () {
+            const { ReconnectionPolicy } = policies.reconnection;
+            class CustomPolicy extends ReconnectionPolicy {}
+            assert.throws(
+                () =>
+                    getRustOptions({
+                        policies: { reconnection: new CustomPolicy() },
+                    }),
+                errors.ArgumentError,
+            );
+        });
+
+        it("should map the monotonic timestamp generator", function () {
+            const { MonotonicTimestampGenerator } = policies.timestampGeneration;
+            const rustOptions = getRustOptions({
+                policies: {
+                    timestampGeneration: new MonotonicTimestampGenerator(
+                        2000,
+                        500,
+                    ),
+                },
+            });
+            assert.deepEqual(rustOptions.timestampGenerator, {
+                warningThreshold: 2000,
+                minLogInterval: 500,
+            });
+            // Values under 1 disable logging
+            assert.strictEqual(
+                getRustOptions({
+                    policies: {
+                        timestampGeneration: new MonotonicTimestampGenerator(
+                            2000,
+                            -1,
+                        ),
+                    },
+                }).timestampGenerator.minLogInterval,
+                0,
+            );
+        });
+
+        it("should map the default timestamp generator", function () {
+            assert.deepEqual(getRustOptions({}).timestampGenerator, {
+                warningThreshold: 1000,
+                minLogInterval: 1000,
+            });
+        });
+
+        it("should disable client side timestamps", function () {
+            const rustOptions = getRustOptions({
+                policies: { timestampGeneration: null },
+            });
+            assert.isUndefined(rustOptions.timestampGenerator);
+        });
+
+        it("should reject custom timestamp generators", function () {
+            const { MonotonicTimestampGenerator, TimestampGenerator } =
+                policies.timestampGeneration;
+            class CustomGenerator extends TimestampGenerator {
+                next() {
+                    return 1;
+                }
+            }
+            class CustomMonotonicGenerator extends MonotonicTimestampGenerator {
+                getDate() {
+                    return 1;
+                }
+            }
+            for (const generator of [
+                new CustomGenerator(),
+                new CustomMonotonicGenerator(),
+            ]) {
+                assert.throws(
+                    () =>
+                        getRustOptions({
+                            policies: { timestampGeneration: generator },
+                        }),
+                    errors.ArgumentError,
+                );
+            }
+        });
+    });
+
+    describe("session options in Rust", function () {
+        /**
+         * @param {Object} pooling Pooling options passed to the Rust session
+         * @param {Object} [options] Other options passed to the Rust session
+         * @returns {Promise<Error>} Error of the session creation
+         */
+        async function getSessionError(pooling, options) {
+            try {
+                // Options are validated before connecting to the contact points
+                await rust.SessionWrapper.createSession({
+                    connectPoints: [],
+                    pooling,
+                    ...options,
+                });
+            } catch (err) {
+                return err;
+            }
+            throw new Error("Session creation should have failed");
+        }
+
+        it("should reject connections both per host and per shard", async function () {
+            const error = await getSessionError({
+                coreConnectionsPerHost: 1,
+                coreConnectionsPerShard: 1,
+            });
+            assert.include(error.message, "both per host and per shard");
+        });
+
+        it("should reject zero connections", async function () {
+            const error = await getSessionError({ coreConnectionsPerShard: 0 });
+            assert.include(error.message, "must be positive");
+        });
+
+        it("should reject unsupported compression", async function () {
+            for (const compression of ["gzip", "LZ4", ""]) {
+                const error = await getSessionError({}, { compression });
+                assert.include(error.message, "Unsupported compression");
+            }
+        });
+
+        it("should accept supported compression", async function () {
+            for (const compression of ["lz4", "snappy"]) {
+                const error = await getSessionError({}, { compression });
+                assert.notInclude(error.message, "compression");
+            }
+        });
+
+        it("should reject invalid shard-aware local port range", async function () {
+            const error = await getSessionError({
+                shardAwareLocalPortRange: { min: 80, max: 1000 },
+            });
+            assert.include(
+                error.message,
+                "Invalid shard-aware local port range",
+            );
+        });
+    });
+
+    describe("socket options in Rust", function () {
+        const { testsSocketOptions } = rust;
+
+        it("should enable keep-alive by default", function () {
+            const config = testsSocketOptions({});
+            assert.strictEqual(config.keepAliveDelay, 2 * 60 * 60 * 1000);
+            assert.strictEqual(
+                testsSocketOptions({ keepAlive: true, keepAliveDelay: 0 })
+                    .keepAliveDelay,
+                2 * 60 * 60 * 1000,
+            );
+        });
+
+        it("should map the socket options", function () {
+            const config = testsSocketOptions({
+                connectTimeout: 1000,
+                keepAlive: true,
+                keepAliveDelay: 30000,
+                tcpNoDelay: false,
+            });
+            assert.deepEqual(config, {
+                connectTimeout: 1000,
+                keepAliveDelay: 30000,
+                tcpNoDelay: false,
+            });
+        });
+
+        it("should disable keep-alive", function () {
+            const config = testsSocketOptions({
+                keepAlive: false,
+                keepAliveDelay: 30000,
+            });
+            assert.isNull(config.keepAliveDelay);
+        });
+
+        it("should reject invalid times", function () {
+            for (const socketOptions of [
+                { connectTimeout: 0 },
+                { connectTimeout: -1 },
+                { keepAliveDelay: 1.5 },
+                { keepAliveDelay: NaN },
+            ]) {
+                assert.throws(
+                    () => testsSocketOptions(socketOptions),
+                    /must be/,
+                );
+            }
+        });
+
+        it("should provide the socket options of the client by default", function () {
+            const rustOptions = clientOptions.setRustOptions({
+                contactPoints: ["127.0.0.1"],
+            });
+            assert.deepEqual(rustOptions.socketOptions, {
+                connectTimeout: undefined,
+                keepAlive: undefined,
+                keepAliveDelay: undefined,
+                tcpNoDelay: undefined,
+            });
+        });
+    });
+
+    describe("extend()", function () {
+        it("should set the default shutdownTimeout", function () {
+            const options = clientOptions.extend({
+                contactPoints: ["127.0.0.1"],
+            });
+            assert.strictEqual(options.shutdownTimeout, 10000);
+        });
+
+        it("should validate shutdownTimeout", function () {
+            [-1, "1000", NaN, null].forEach((shutdownTimeout) =>
+                assert.throws(
+                    () =>
+                        clientOptions.extend({
+                            contactPoints: ["127.0.0.1"],
+                            shutdownTimeout,
+                        }),
+                    TypeError,
+                    /shutdownTimeout/,
+                ),
+            );
+            assert.doesNotThrow(() =>
+                clientOptions.extend({
+                    contactPoints: ["127.0.0.1"],
+                    shutdownTimeout: 0,
+                }),
+            );
+        });
+    });
+});
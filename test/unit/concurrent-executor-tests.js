@@ -0,0 +1,154 @@
+"use strict";
+const { assert } = require("chai");
+const { Readable } = require("stream");
+const { executeConcurrent } = require("../../lib/concurrent");
+const errors = require("../../lib/errors");
+
+/**
+ * Creates a client that records the parameters pushed to the executor,
+ * instead of executing them with the Rust driver.
+ * @param {function(Array<Array>, number): boolean} [onPush] Returns whether the chunk was accepted
+ */
+function getFakeClient(onPush) {
+    const client = {
+        chunks: [],
+        finished: false,
+        createOptions: () => ({ getEncodingOptions: () => ({}) }),
+        rustyConcurrentExecutor: async (query, queryOptions, concurrency) => {
+            client.concurrency = concurrency;
+            return {
+                push: async (chunk) => {
+                    client.chunks.push(chunk);
+                    return onPush ? onPush(chunk, client.chunks.length) : true;
+                },
+                finish: async () => {
+                    client.finished = true;
+                    const totalExecuted = client.chunks.flat().length;
+                    return { totalExecuted, errors: [], results: null };
+                },
+            };
+        },
+    };
+    return client;
+}
+
+function getParameters(length) {
+    return Array.from({ length }, (_, i) => [i]);
+}
+
+describe("executeConcurrent()", function () {
+    const query = "INSERT INTO t (id) VALUES (?)";
+
+    describe("with an Array of parameters", function () {
+        it("should push the parameters in chunks of the concurrency level", async function () {
+            const client = getFakeClient();
+            const result = await executeConcurrent(
+                client,
+                query,
+                getParameters(25),
+                { concurrencyLevel: 10 },
+            );
+
+            assert.deepEqual(client.chunks.map((c) => c.length), [10, 10, 5]);
+            assert.deepEqual(client.chunks.flat(), getParameters(25));
+            assert.isTrue(client.finished);
+            assert.strictEqual(result.totalExecuted, 25);
+        });
+
+        it("should stop pushing when the execution stopped", async function () {
+            const client = getFakeClient((chunk, count) => count < 2);
+            await executeConcurrent(client, query, getParameters(100), {
+                concurrencyLevel: 10,
+            });
+
+            assert.lengthOf(client.chunks, 2);
+            assert.isTrue(client.finished);
+        });
+
+        it("should finish the execution when pushing fails", async function () {
+            const client = getFakeClient(() => {
+                throw new errors.ShutdownError("The client has been shut down");
+            });
+            const err = await executeConcurrent(
+                client,
+                query,
+                getParameters(5),
+            ).then(
+                () => assert.fail("It should have been rejected"),
+                (err) => err,
+            );
+
+            assert.instanceOf(err, errors.ShutdownError);
+            assert.isTrue(client.finished);
+        });
+
+        it("should not create the executor without parameters", async function () {
+            const client = getFakeClient();
+            const result = await executeConcurrent(client, query, []);
+
+            assert.isUndefined(client.concurrency);
+            assert.strictEqual(result.totalExecuted, 0);
+        });
+    });
+
+    describe("with a Stream of parameters", function () {
+        it("should push all the items of the stream", async function () {
+            const client = getFakeClient();
+            const stream = Readable.from(getParameters(25));
+            const result = await executeConcurrent(client, query, stream, {
+                concurrencyLevel: 10,
+            });
+
+            assert.deepEqual(client.chunks.flat(), getParameters(25));
+            client.chunks.forEach((chunk) => assert.isAtMost(chunk.length, 10));
+            assert.isTrue(client.finished);
+            assert.strictEqual(result.totalExecuted, 25);
+        });
+
+        it("should stop reading the stream when the execution stopped", async function () {
+            const client = getFakeClient(() => false);
+            let read = 0;
+            const stream = new Readable({
+                objectMode: true,
+                read() {
+                    read++;
+                    this.push(read <= 1000 ? [read] : null);
+                },
+            });
+            await executeConcurrent(client, query, stream);
+
+            assert.lengthOf(client.chunks, 1);
+            assert.isBelow(read, 1000);
+            assert.isTrue(client.finished);
+        });
+
+        it("should reject items that are not Arrays and finish the execution", async function () {
+            const client = getFakeClient();
+            const stream = Readable.from([[1], { id: 2 }]);
+            const err = await executeConcurrent(client, query, stream).then(
+                () => assert.fail("It should have been rejected"),
+                (err) => err,
+            );
+
+            assert.instanceOf(err, TypeError);
+            assert.isTrue(client.finished);
+        });
+
+        it("should finish the execution when reading the stream fails", async function () {
+            const client = getFakeClient();
+            const stream = new Readable({
+                objectMode: true,
+                read() {
+                    this.destroy(new Error("Read failure"));
+                },
+            });
+            const err = await executeConcurrent(client, query, stream).then(
+                () => assert.fail("It should have been rejected"),
+                (err) => err,
+            );
+
+            assert.strictEqual(err.message, "Read failure");
+            assert.isTrue(client.finished);
+        });
+    });
+});
@@ -9,8 +9,10 @@ const LocalTime = require("../../lib/types/local-time");
 const Long = require("long");
 const InetAddress = require("../../lib/types/inet-address");
 const LocalDate = require("../../lib/types/local-date");
+const Row = require("../../lib/types/row");
 const Tuple = require("../../lib/types/tuple");
 const BigDecimal = require("../../lib/types/big-decimal");
+const Vector = require("../../lib/types/vector");
 
 const maxI64 = BigInt("9223372036854775807");
 const maxI32 = Number(2147483647);
@@ -165,6 +167,35 @@ describe("Cql value wrapper", function () {
         );
     });
 
+    it("should decode timestamp in the selected representation", function () {
+        /* Corresponding value:
+        let element = CqlValue::Timestamp(CqlTimestamp(1_000_000_i64)); */
+        let date = rust.testsGetCqlWrapperTimestampDecoded(
+            rust.TimestampDecoding.Date,
+        );
+        assert.instanceOf(date, Date);
+        assert.strictEqual(date.getTime(), 1000000);
+        assert.strictEqual(
+            rust.testsGetCqlWrapperTimestampDecoded(
+                rust.TimestampDecoding.Number,
+            ),
+            1000000,
+        );
+        assert.strictEqual(
+            rust.testsGetCqlWrapperTimestampDecoded(
+                rust.TimestampDecoding.BigInt,
+            ),
+            BigInt(1000000),
+        );
+        let value = getCqlObject(
+            rust.testsGetCqlWrapperTimestampDecoded(
+                rust.TimestampDecoding.Number,
+            ),
+            { code: rust.CqlType.Timestamp },
+        );
+        assert.strictEqual(value, 1000000);
+    });
+
     it("should get list type correctly from napi", function () {
         let element = rust.testsGetCqlWrapperList();
         let value = getCqlObject(element, {
@@ -239,6 +270,107 @@ describe("Cql value wrapper", function () {
         });
     });
 
+    it("should keep the name of udt type as non-enumerable property", function () {
+        let [value] = rust.testsGetCqlWrapperUdtList(null);
+        assert.strictEqual(value.udtName, "ks.address");
+        assert.deepEqual(Object.keys(value), ["street", "id"]);
+    });
+
+    it("should create udt values with the registered constructors", function () {
+        class Address {}
+        let element = rust.testsGetCqlWrapperUdtList({ "ks.address": Address });
+        let value = getCqlObject(element, {
+            code: rust.CqlType.List,
+            info: {
+                code: rust.CqlType.UserDefinedType,
+                info: {
+                    name: "address",
+                    fields: [
+                        { name: "street", type: { code: rust.CqlType.Text } },
+                        { name: "id", type: { code: rust.CqlType.Uuid } },
+                    ],
+                },
+            },
+        });
+        /* Corresponding value:
+        let element = CqlValue::List(vec![CqlValue::UserDefinedType {
+            keyspace: String::from("ks"),
+            name: String::from("address"),
+            fields: vec![
+                (
+                    String::from("street"),
+                    Some(CqlValue::Text("some street".to_owned())),
+                ),
+                (
+                    String::from("id"),
+                    Some(CqlValue::Uuid(uuid!("ffffffff-ffff-ffff-eeee-ffffffffffff"))),
+                ),
+            ],
+        }]); */
+        assert.strictEqual(value.length, 1);
+        assert.instanceOf(value[0], Address);
+        assert.strictEqual(value[0].udtName, "ks.address");
+        assert.strictEqual(value[0].street, "some street");
+        assert.instanceOf(value[0].id, Uuid);
+        assert.isTrue(
+            value[0].id.equals(
+                Uuid.fromString("ffffffff-ffff-ffff-eeee-ffffffffffff"),
+            ),
+        );
+    });
+
+    const addressListType = {
+        code: rust.CqlType.List,
+        info: {
+            code: rust.CqlType.UserDefinedType,
+            info: {
+                keyspace: "ks",
+                name: "address",
+                fields: [
+                    { name: "street", type: { code: rust.CqlType.Text } },
+                    { name: "id", type: { code: rust.CqlType.Uuid } },
+                ],
+            },
+        },
+    };
+
+    it("should keep the udtName property defined by the registered class", function () {
+        class Address {
+            get udtName() {
+                return "address";
+            }
+        }
+        let element = rust.testsGetCqlWrapperUdtList({ "ks.address": Address });
+        let value = getCqlObject(element, addressListType);
+        assert.instanceOf(value[0], Address);
+        assert.strictEqual(value[0].udtName, "address");
+        assert.strictEqual(value[0].street, "some street");
+    });
+
+    it("should create udt values with the registered factories", function () {
+        const fieldsOfValues = [];
+        const factory = (fields) => {
+            fieldsOfValues.push(fields);
+            return Object.freeze({ ...fields, kind: "address" });
+        };
+        let element = rust.testsGetCqlWrapperUdtList(null);
+        let value = getCqlObject(element, addressListType, {
+            udtFactories: { "ks.address": factory },
+        });
+        assert.strictEqual(fieldsOfValues.length, 1);
+        assert.strictEqual(fieldsOfValues[0].udtName, "ks.address");
+        // Fields are decoded before the factory is called
+        assert.instanceOf(fieldsOfValues[0].id, Uuid);
+        assert.isTrue(Object.isFrozen(value[0]));
+        assert.strictEqual(value[0].kind, "address");
+        assert.strictEqual(value[0].street, "some street");
+        assert.isTrue(
+            value[0].id.equals(
+                Uuid.fromString("ffffffff-ffff-ffff-eeee-ffffffffffff"),
+            ),
+        );
+    });
+
     it("should get map type correctly from napi", function () {
         let element = rust.testsGetCqlWrapperMap();
         let value = getCqlObject(element, {
@@ -257,6 +389,37 @@ describe("Cql value wrapper", function () {
         assert.deepStrictEqual(value, z);
     });
 
+    it("should get map type as Map when map constructor is provided", function () {
+        let element = rust.testsGetCqlWrapperMap();
+        let value = getCqlObject(
+            element,
+            {
+                code: rust.CqlType.Map,
+                info: [{ code: rust.CqlType.Uuid }, { code: rust.CqlType.Int }],
+            },
+            { map: Map },
+        );
+        assert.instanceOf(value, Map);
+        assert.strictEqual(value.size, 1);
+        let [[key, mapValue]] = value;
+        assert.instanceOf(key, Uuid);
+        assert.isTrue(
+            key.equals(Uuid.fromString("ffffffff-ffff-ffff-eeee-ffffffffffff")),
+        );
+        assert.strictEqual(mapValue, 1999);
+    });
+
+    it("should get set type as Set when set constructor is provided", function () {
+        let element = rust.testsGetCqlWrapperSet();
+        let value = getCqlObject(
+            element,
+            { code: rust.CqlType.Set, info: { code: rust.CqlType.Text } },
+            { set: Set },
+        );
+        assert.instanceOf(value, Set);
+        assert.deepEqual(Array.from(value), ["some text", "other text"]);
+    });
+
     it("should get small int type correctly from napi", function () {
         let element = rust.testsGetCqlWrapperSmallInt();
         let value = getCqlObject(element, { code: rust.CqlType.SmallInt });
@@ -362,4 +525,78 @@ describe("Cql value wrapper", function () {
         let element = CqlValue::Varint(CqlVarint::from_signed_bytes_be_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13])) */
         assert.strictEqual(value, BigInt("-10140582186046599701377542583283"));
     });
+
+    it("should get vector of floats as typed array from napi", function () {
+        let element = rust.testsGetCqlWrapperFloatVector();
+        assert.instanceOf(element, Float32Array);
+        let value = getCqlObject(element, {
+            code: rust.CqlType.Custom,
+            customTypeName: "vector",
+            info: [{ code: rust.CqlType.Float }, 3],
+        });
+        /* Corresponding value:
+        let element = CqlValue::Vector(vec![
+            CqlValue::Float(1.5),
+            CqlValue::Float(-2.25),
+            CqlValue::Float(3.0),
+        ]); */
+        assert.instanceOf(value, Vector);
+        assert.strictEqual(value.elements, element);
+        assert.strictEqual(value.getSubtype(), "float");
+        assert.deepEqual(Array.from(value), [1.5, -2.25, 3.0]);
+    });
+
+    it("should get vector of texts correctly from napi", function () {
+        let element = rust.testsGetCqlWrapperTextVector();
+        let value = getCqlObject(element, {
+            code: rust.CqlType.Custom,
+            customTypeName: "vector",
+            info: [{ code: rust.CqlType.Text }, 2],
+        });
+        /* Corresponding value:
+        let element = CqlValue::Vector(vec![
+            CqlValue::Text("some text".to_owned()),
+            CqlValue::Text("other text".to_owned()),
+        ]); */
+        assert.instanceOf(value, Vector);
+        assert.deepEqual(Array.from(value), ["some text", "other text"]);
+    });
+});
+
+describe("Rows created from rust", function () {
+    it("should create the rows with the provided constructor and shared column names", function () {
+        const columnNames = [];
+        const rows = rust.testsGetRowsAsObjects(function (names) {
+            columnNames.push(names);
+            return new Row(names);
+        });
+        /* Corresponding rows:
+        [CqlValue::Int(1), CqlValue::Text("first".to_owned())],
+        [CqlValue::Int(2), CqlValue::Text("second".to_owned())],
+        [CqlValue::Int(3), CqlValue::Text("third".to_owned())], */
+        assert.strictEqual(rows.length, 3);
+        assert.strictEqual(columnNames.length, 3);
+        assert.deepEqual(columnNames[0], ["id", "name"]);
+        // The same array of the column names is provided for each row
+        assert.strictEqual(columnNames[1], columnNames[0]);
+        assert.strictEqual(columnNames[2], columnNames[0]);
+        rows.forEach((row) => assert.instanceOf(row, Row));
+        assert.deepEqual(
+            rows.map((row) => Object.keys(row)),
+            [
+                ["id", "name"],
+                ["id", "name"],
+                ["id", "name"],
+            ],
+        );
+        assert.deepEqual(
+            rows.map((row) => row.values()),
+            [
+                [1, "first"],
+                [2, "second"],
+                [3, "third"],
+            ],
+        );
+        assert.strictEqual(rows[1].get(1), "second");
+    });
 });
@@ -0,0 +1,114 @@
+"use strict";
+const assert = require("assert");
+const {
+    DateRange,
+    DateRangeBound,
+    dateRangePrecision,
+} = require("../../lib/datastax/search");
+
+describe("DateRange", function () {
+    describe("fromString() and #toString()", function () {
+        it("should parse and format the date ranges", function () {
+            [
+                "2015",
+                "2015-02",
+                "2015-02-10T10:15:30.123Z",
+                "*",
+                "[2015 TO 2016-01-30]",
+                "[* TO 2016-01-30T23:59Z]",
+                "[1969-12-31T23:59:59Z TO *]",
+                "[* TO *]",
+            ].forEach((value) =>
+                assert.strictEqual(
+                    DateRange.fromString(value).toString(),
+                    value,
+                ),
+            );
+        });
+
+        it("should set the bounds and their precision", function () {
+            const range = DateRange.fromString("[2015-02 TO *]");
+            assert.strictEqual(
+                range.lowerBound.precision,
+                dateRangePrecision.month,
+            );
+            assert.strictEqual(
+                range.lowerBound.date.getTime(),
+                Date.UTC(2015, 1, 1),
+            );
+            assert.ok(range.upperBound.isUnbounded());
+            assert.strictEqual(DateRange.fromString("2015").upperBound, null);
+        });
+
+        it("should throw TypeError for invalid strings", function () {
+            ["", "2015-13", "2015-02-29", "[2015 TO]", "2015 TO 2016"].forEach(
+                (value) =>
+                    assert.throws(() => DateRange.fromString(value), TypeError),
+            );
+        });
+    });
+
+    describe("fromBuffer() and #toBuffer()", function () {
+        it("should serialize and deserialize the date ranges", function () {
+            ["2015-02-10T10:15Z", "*", "[2015 TO 2016]", "[* TO 2016]"].forEach(
+                (value) => {
+                    const range = DateRange.fromString(value);
+                    assert.ok(
+                        DateRange.fromBuffer(range.toBuffer()).equals(range),
+                    );
+                },
+            );
+        });
+
+        it("should serialize the closed range", function () {
+            const range = new DateRange(
+                new DateRangeBound(new Date(0), dateRangePrecision.year),
+                new DateRangeBound(new Date(1), dateRangePrecision.millisecond),
+            );
+            assert.deepStrictEqual(
+                range.toBuffer(),
+                Buffer.from([
+                    1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 6,
+                ]),
+            );
+        });
+
+        it("should throw TypeError for invalid buffers", function () {
+            assert.throws(
+                () => DateRange.fromBuffer(Buffer.from([0, 1])),
+                TypeError,
+            );
+        });
+    });
+
+    describe("#equals()", function () {
+        it("should compare the bounds", function () {
+            const range = DateRange.fromString("[2015 TO *]");
+            assert.ok(range.equals(DateRange.fromString("[2015 TO *]")));
+            assert.ok(!range.equals(DateRange.fromString("[2015-01 TO *]")));
+            assert.ok(!range.equals(DateRange.fromString("2015")));
+        });
+    });
+});
+
+describe("DateRangeBound", function () {
+    it("should round the bounds to the precision", function () {
+        const bound = DateRangeBound.fromString("2016-02");
+        assert.strictEqual(
+            DateRangeBound.toLowerBound(bound).date.getTime(),
+            Date.UTC(2016, 1, 1),
+        );
+        assert.strictEqual(
+            DateRangeBound.toUpperBound(bound).date.getTime(),
+            Date.UTC(2016, 1, 29, 23, 59, 59, 999),
+        );
+    });
+
+    it("should represent the unbounded bound", function () {
+        assert.strictEqual(
+            DateRangeBound.fromString("*"),
+            DateRangeBound.unbounded,
+        );
+        assert.strictEqual(DateRangeBound.unbounded.toString(), "*");
+    });
+});
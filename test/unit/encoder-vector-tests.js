@@ -85,4 +85,24 @@ describe("Vector tests", function () {
             assert.strictEqual(decoded[i], vector[i]);
         }
     });
+
+    [
+        { array: new Float32Array([1.5, -2.25, 3]), subtype: "float" },
+        { array: new Float64Array([1.1, -2.2, 3.3]), subtype: "double" },
+        { array: new Int32Array([1, -2, 2147483647]), subtype: "int" },
+    ].forEach(({ array, subtype }) => {
+        it(`should encode ${array.constructor.name} as vector of ${subtype} in big endian`, function () {
+            const typeObj = types.dataTypes.getByName(`vector<${subtype}, 3>`);
+            const expected = encoder.encode(
+                new Vector(Array.from(array), subtype),
+                typeObj,
+            );
+            assert.deepEqual(encoder.encode(array, typeObj), expected);
+            assert.deepEqual(
+                encoder.encode(new Vector(array), typeObj),
+                expected,
+            );
+            assert.deepEqual(Encoder.guessDataType(array), typeObj);
+        });
+    });
 });
@@ -1,8 +1,9 @@
 "use strict";
 const { assert } = require("chai");
 const { napiErrorHandler } = require("../../lib/new-utils");
-const { throwTestError } = require("../../index");
+const { panicTestError, throwTestError } = require("../../index");
 const {
+    AbortError,
     ArgumentError,
     AuthenticationError,
     BusyConnectionError,
@@ -12,6 +13,7 @@ const {
     NotSupportedError,
     OperationTimedOutError,
     ResponseError,
+    ShutdownError,
 } = require("../../lib/errors");
 
 class SomeJsClass {
@@ -27,10 +29,22 @@ class SomeJsClass {
     throwSimple = napiErrorHandler(function () {
         throw new Error("Simple error");
     });
+    // panics in Rust
+    panic = napiErrorHandler(function () {
+        return panicTestError();
+    });
 }
 describe("napiErrorHandler", function () {
     const classInstance = new SomeJsClass();
 
+    it("should throw AbortError", function () {
+        assert.throws(
+            () => classInstance.throwError("AbortError"),
+            AbortError,
+            "Test error",
+        );
+    });
+
     it("should throw ArgumentError", function () {
         assert.throws(
             () => classInstance.throwError("ArgumentError"),
@@ -107,6 +121,14 @@ describe("napiErrorHandler", function () {
     //     );
     // });
 
+    it("should throw ShutdownError", function () {
+        assert.throws(
+            () => classInstance.throwError("ShutdownError"),
+            ShutdownError,
+            "Test error",
+        );
+    });
+
     it("should throw Error", function () {
         assert.throws(
             () => classInstance.throwError("Error"),
@@ -166,4 +188,8 @@ describe("napiErrorHandler", function () {
     it("should rethrow simple error", function () {
         assert.throws(() => classInstance.throwSimple(), Error, "Simple error");
     });
+
+    it("should throw Error on panic", function () {
+        assert.throws(() => classInstance.panic(), Error, "Test panic");
+    });
 });
@@ -0,0 +1,183 @@
+"use strict";
+const { assert } = require("chai");
+const Client = require("../../lib/client");
+const errors = require("../../lib/errors");
+const { Host } = require("../../lib/host");
+const { DefaultExecutionOptions } = require("../../lib/execution-options");
+
+describe("DefaultExecutionOptions", function () {
+    describe("#getEncodingOptions()", function () {
+        const client = new Client({
+            contactPoints: ["127.0.0.1"],
+            localDataCenter: "dc1",
+            encoding: { map: Map },
+        });
+
+        it("should use the encoding options of the client by default", function () {
+            const options = DefaultExecutionOptions.create({}, client);
+            assert.strictEqual(
+                options.getEncodingOptions(),
+                client.options.encoding,
+            );
+        });
+
+        it("should override the encoding options of the client", function () {
+            const options = DefaultExecutionOptions.create(
+                { encoding: { set: Set } },
+                client,
+            );
+            assert.strictEqual(options.getEncodingOptions().map, Map);
+            assert.strictEqual(options.getEncodingOptions().set, Set);
+        });
+
+        it("should validate the encoding options of the execution", function () {
+            assert.throws(
+                () =>
+                    DefaultExecutionOptions.create(
+                        { encoding: { map: Array } },
+                        client,
+                    ),
+                TypeError,
+                "Map constructor not valid",
+            );
+            assert.throws(
+                () =>
+                    DefaultExecutionOptions.create(
+                        { encoding: { set: {} } },
+                        client,
+                    ),
+                TypeError,
+                "Set constructor not valid",
+            );
+            assert.throws(
+                () =>
+                    DefaultExecutionOptions.create(
+                        { encoding: { udtConstructors: { "ks.udt": {} } } },
+                        client,
+                    ),
+                TypeError,
+                "Constructor of the user defined type ks.udt not valid",
+            );
+            assert.throws(
+                () =>
+                    DefaultExecutionOptions.create(
+                        { encoding: { udtFactories: { "ks.udt": {} } } },
+                        client,
+                    ),
+                TypeError,
+                "Factory of the user defined type ks.udt not valid",
+            );
+        });
+
+        it("should validate the timestamp decoding of the execution", function () {
+            assert.throws(
+                () =>
+                    DefaultExecutionOptions.create(
+                        { encoding: { timestampDecoding: "Date" } },
+                        client,
+                    ),
+                TypeError,
+                "Timestamp decoding Date not valid",
+            );
+            ["date", "number", "bigint"].forEach((timestampDecoding) => {
+                const options = DefaultExecutionOptions.create(
+                    { encoding: { timestampDecoding } },
+                    client,
+                );
+                assert.strictEqual(
+                    options.getEncodingOptions().timestampDecoding,
+                    timestampDecoding,
+                );
+            });
+        });
+
+        it("should not allow both a constructor and a factory of the same user defined type", function () {
+            const clientWithConstructors = new Client({
+                contactPoints: ["127.0.0.1"],
+                localDataCenter: "dc1",
+                encoding: { udtConstructors: { "ks.udt": class {} } },
+            });
+            assert.throws(
+                () =>
+                    DefaultExecutionOptions.create(
+                        {
+                            encoding: {
+                                udtFactories: { "ks.udt": (fields) => fields },
+                            },
+                        },
+                        clientWithConstructors,
+                    ),
+                TypeError,
+                "User defined type ks.udt can't have both a constructor and a factory",
+            );
+            const options = DefaultExecutionOptions.create(
+                {
+                    encoding: {
+                        udtConstructors: {},
+                        udtFactories: { "ks.udt": (fields) => fields },
+                    },
+                },
+                clientWithConstructors,
+            );
+            assert.isFunction(
+                options.getEncodingOptions().udtFactories["ks.udt"],
+            );
+        });
+    });
+
+    describe("#getRustOptions()", function () {
+        const client = new Client({
+            contactPoints: ["127.0.0.1"],
+            localDataCenter: "dc1",
+        });
+
+        /**
+         * @param {Object} [hostId]
+         * @returns {Host}
+         */
+        function createHost(hostId) {
+            const host = new Host();
+            host.address = "127.0.0.1:9042";
+            host.hostId = hostId;
+            return host;
+        }
+
+        it("should map the target host and shard", function () {
+            const host = createHost(Buffer.alloc(16, 1));
+            for (const shard of [undefined, null, 0, 3]) {
+                const options = DefaultExecutionOptions.create(
+                    { host, shard },
+                    client,
+                );
+                assert.isOk(options.getRustOptions());
+            }
+        });
+
+        it("should reject target hosts without host ID", function () {
+            const options = DefaultExecutionOptions.create(
+                { host: createHost(null) },
+                client,
+            );
+            assert.throws(
+                () => options.getRustOptions(),
+                errors.ArgumentError,
+                "The target host must have a host ID: 127.0.0.1:9042",
+            );
+        });
+
+        it("should reject invalid shards", function () {
+            const host = createHost(Buffer.alloc(16, 1));
+            for (const shard of [-1, 1.5, "0", NaN]) {
+                const options = DefaultExecutionOptions.create(
+                    { host, shard },
+                    client,
+                );
+                assert.throws(
+                    () => options.getRustOptions(),
+                    errors.ArgumentError,
+                    "The shard must be a non-negative integer",
+                );
+            }
+        });
+    });
+});
@@ -0,0 +1,112 @@
+"use strict";
+const assert = require("assert");
+const { LineString, Point, Polygon } = require("../../lib/geometry");
+
+describe("Point", function () {
+    it("should parse and format the WKT representation", function () {
+        const point = Point.fromString("POINT (1.5 -2)");
+        assert.strictEqual(point.x, 1.5);
+        assert.strictEqual(point.y, -2);
+        assert.strictEqual(point.toString(), "POINT (1.5 -2)");
+    });
+
+    it("should serialize and deserialize the WKB representation", function () {
+        const point = new Point(1, 2);
+        const buffer = point.toBuffer();
+        assert.strictEqual(buffer.length, 21);
+        assert.ok(Point.fromBuffer(buffer).equals(point));
+    });
+
+    it("should deserialize the big-endian WKB representation", function () {
+        const buffer = Buffer.alloc(21);
+        buffer.writeUInt8(0, 0);
+        buffer.writeUInt32BE(1, 1);
+        buffer.writeDoubleBE(3, 5);
+        buffer.writeDoubleBE(4, 13);
+        assert.ok(Point.fromBuffer(buffer).equals(new Point(3, 4)));
+    });
+
+    it("should throw TypeError for invalid values", function () {
+        assert.throws(() => new Point(1, NaN), TypeError);
+        assert.throws(() => Point.fromString("POINT (1)"), TypeError);
+        assert.throws(() => Point.fromString("LINESTRING EMPTY"), TypeError);
+        assert.throws(() => Point.fromBuffer(Buffer.from([1, 1])), TypeError);
+    });
+
+    it("should return the GeoJSON representation", function () {
+        assert.deepStrictEqual(new Point(1, 2).toJSON(), {
+            type: "Point",
+            coordinates: [1, 2],
+        });
+    });
+});
+
+describe("LineString", function () {
+    it("should parse and format the WKT representation", function () {
+        ["LINESTRING (30 10, 10 30, 40 40)", "LINESTRING EMPTY"].forEach(
+            (value) =>
+                assert.strictEqual(
+                    LineString.fromString(value).toString(),
+                    value,
+                ),
+        );
+    });
+
+    it("should accept the points as arguments or as an array", function () {
+        const points = [new Point(1, 2), new Point(3, 4)];
+        const line = new LineString(...points);
+        assert.ok(line.equals(new LineString(points)));
+        assert.ok(!line.equals(new LineString(points[0])));
+        assert.ok(Object.isFrozen(line.points));
+    });
+
+    it("should serialize and deserialize the WKB representation", function () {
+        const line = LineString.fromString("LINESTRING (30 10, 10 30)");
+        assert.ok(LineString.fromBuffer(line.toBuffer()).equals(line));
+        assert.throws(() => Point.fromBuffer(line.toBuffer()), TypeError);
+    });
+});
+
+describe("Polygon", function () {
+    it("should parse and format the WKT representation", function () {
+        [
+            "POLYGON ((30 10, 40 40, 20 40, 10 20, 30 10))",
+            "POLYGON ((35 10, 45 45, 15 40, 35 10), (20 30, 35 35, 30 20, 20 30))",
+            "POLYGON EMPTY",
+        ].forEach((value) =>
+            assert.strictEqual(Polygon.fromString(value).toString(), value),
+        );
+    });
+
+    it("should serialize and deserialize the WKB representation", function () {
+        const polygon = new Polygon(
+            [
+                new Point(1, 1),
+                new Point(3, 1),
+                new Point(2, 3),
+                new Point(1, 1),
+            ],
+            [new Point(2, 2), new Point(2.5, 2), new Point(2, 2)],
+        );
+        const deserialized = Polygon.fromBuffer(polygon.toBuffer());
+        assert.ok(deserialized.equals(polygon));
+        assert.strictEqual(deserialized.rings.length, 2);
+    });
+
+    it("should return the GeoJSON representation", function () {
+        assert.deepStrictEqual(
+            Polygon.fromString("POLYGON ((1 1, 3 1, 2 3, 1 1))").toJSON(),
+            {
+                type: "Polygon",
+                coordinates: [
+                    [
+                        [1, 1],
+                        [3, 1],
+                        [2, 3],
+                        [1, 1],
+                    ],
+                ],
+            },
+        );
+    });
+});
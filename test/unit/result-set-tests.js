@@ -20,6 +20,28 @@ describe("ResultSet", function () {
         });
     });
 
+    describe("#getExistingRow()", function () {
+        it("should return the first row when not applied", function () {
+            const row = { "[applied]": false, email: "a@example.com" };
+            const result = new ResultSet({ rows: [row] }, null);
+            assert.strictEqual(result.wasApplied(), false);
+            assert.strictEqual(result.getExistingRow(), row);
+        });
+
+        it("should return null when applied", function () {
+            const result = new ResultSet(
+                { rows: [{ "[applied]": true }] },
+                null,
+            );
+            assert.strictEqual(result.getExistingRow(), null);
+        });
+
+        it("should return null when it's not a conditional update", function () {
+            const result = new ResultSet({ rows: [{ email: "a" }] }, null);
+            assert.strictEqual(result.getExistingRow(), null);
+        });
+    });
+
     describe("#[@@iterator]()", function () {
         it("should return the rows iterator", function () {
             const result = new ResultSet({ rows: [100, 200, 300] });